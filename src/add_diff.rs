@@ -1,6 +1,12 @@
+#[cfg(feature = "chrono")]
+use core::fmt::{self, Display, Formatter};
+
+#[cfg(feature = "chrono")]
 use chrono::{prelude::*, Duration, LocalResult};
 
-use super::{constants::*, DateTimeDiff};
+use super::{constants::*, DateTimeDiff, EndOfMonth};
+#[cfg(feature = "chrono")]
+use super::{AddOptions, DateDiffResult, Disambiguation, TimeSemantics};
 
 #[inline]
 fn month_add(year: &mut i32, month: &mut i32, n: i32) -> Option<()> {
@@ -10,13 +16,8 @@ fn month_add(year: &mut i32, month: &mut i32, n: i32) -> Option<()> {
         *year += *month / 12;
         *month %= 12;
     } else if *month < 0 {
-        *year += *month / 12 - 1;
-
-        *month = 12 - (-*month % 12);
-
-        if *month == 12 {
-            *month = 0;
-        }
+        *year += month.div_euclid(12);
+        *month = month.rem_euclid(12);
     }
 
     Some(())
@@ -70,13 +71,8 @@ fn hour_add(year: &mut i32, month: &mut i32, date: &mut i32, hour: &mut i32, n:
         date_add(year, month, date, *hour / 24)?;
         *hour %= 24;
     } else if *hour < 0 {
-        date_add(year, month, date, *hour / 24 - 1)?;
-
-        *hour = 24 - (-*hour % 24);
-
-        if *hour == 24 {
-            *hour = 0;
-        }
+        date_add(year, month, date, hour.div_euclid(24))?;
+        *hour = hour.rem_euclid(24);
     }
 
     Some(())
@@ -97,13 +93,8 @@ fn minute_add(
         hour_add(year, month, date, hour, *minute / 60)?;
         *minute %= 60;
     } else if *minute < 0 {
-        hour_add(year, month, date, hour, *minute / 60 - 1)?;
-
-        *minute = 60 - (-*minute % 60);
-
-        if *minute == 60 {
-            *minute = 0;
-        }
+        hour_add(year, month, date, hour, minute.div_euclid(60))?;
+        *minute = minute.rem_euclid(60);
     }
 
     Some(())
@@ -125,13 +116,8 @@ fn second_add(
         minute_add(year, month, date, hour, minute, *second / 60)?;
         *second %= 60;
     } else if *second < 0 {
-        minute_add(year, month, date, hour, minute, *second / 60 - 1)?;
-
-        *second = 60 - (-*second % 60);
-
-        if *second == 60 {
-            *second = 0;
-        }
+        minute_add(year, month, date, hour, minute, second.div_euclid(60))?;
+        *second = second.rem_euclid(60);
     }
 
     Some(())
@@ -164,21 +150,157 @@ fn nanosecond_add(
             hour,
             minute,
             second,
-            *nanosecond / SECOND_NANOSECONDS_I32 - 1,
+            nanosecond.div_euclid(SECOND_NANOSECONDS_I32),
         )?;
 
-        *nanosecond = SECOND_NANOSECONDS_I32 - (-*nanosecond % SECOND_NANOSECONDS_I32);
+        *nanosecond = nanosecond.rem_euclid(SECOND_NANOSECONDS_I32);
+    }
 
-        if *nanosecond == 60 {
-            *nanosecond = 0;
+    Some(())
+}
+
+// add `n` months to the year and the month, and then fix the date which may not exist in the new month
+pub(crate) fn month_add_with_end_of_month(
+    year: &mut i32,
+    month: &mut i32,
+    date: &mut i32,
+    n: i32,
+    end_of_month: EndOfMonth,
+) -> Option<()> {
+    let is_month_end =
+        *date == year_helper::get_days_in_month(*year, (*month + 1) as u8).unwrap() as i32;
+
+    month_add(year, month, n)?;
+
+    let days_in_month = year_helper::get_days_in_month(*year, (*month + 1) as u8).unwrap() as i32;
+
+    if *date > days_in_month {
+        match end_of_month {
+            EndOfMonth::Clamp | EndOfMonth::StickToMonthEnd => *date = days_in_month,
+            EndOfMonth::Overflow => date_add(year, month, date, 0)?,
+            EndOfMonth::Reject => return None,
         }
+    } else if is_month_end && end_of_month == EndOfMonth::StickToMonthEnd {
+        *date = days_in_month;
     }
 
     Some(())
 }
 
+#[inline]
+fn total_days(date_time_diff: &dyn DateTimeDiff) -> Option<i32> {
+    date_time_diff.weeks().checked_mul(7)?.checked_add(date_time_diff.days())
+}
+
+// the fields of a date-time on the wall clock, where `month` is 0-based
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WallClock {
+    pub(crate) year:       i32,
+    pub(crate) month:      i32,
+    pub(crate) date:       i32,
+    pub(crate) hour:       i32,
+    pub(crate) minute:     i32,
+    pub(crate) second:     i32,
+    pub(crate) nanosecond: i32,
+}
+
+// add the years and the months first, then the days, and the time last
+pub(crate) fn _add_wall_clock(
+    mut v: WallClock,
+    date_time_diff: &dyn DateTimeDiff,
+    end_of_month: EndOfMonth,
+) -> Option<WallClock> {
+    // adding years is the same as adding 12 months, except for the overflow
+    let months = date_time_diff.years().checked_mul(12)?.checked_add(date_time_diff.months())?;
+
+    month_add_with_end_of_month(&mut v.year, &mut v.month, &mut v.date, months, end_of_month)?;
+
+    date_add(&mut v.year, &mut v.month, &mut v.date, total_days(date_time_diff)?)?;
+
+    hour_add(&mut v.year, &mut v.month, &mut v.date, &mut v.hour, date_time_diff.hours())?;
+
+    minute_add(
+        &mut v.year,
+        &mut v.month,
+        &mut v.date,
+        &mut v.hour,
+        &mut v.minute,
+        date_time_diff.minutes(),
+    )?;
+
+    second_add(
+        &mut v.year,
+        &mut v.month,
+        &mut v.date,
+        &mut v.hour,
+        &mut v.minute,
+        &mut v.second,
+        date_time_diff.seconds(),
+    )?;
+
+    nanosecond_add(
+        &mut v.year,
+        &mut v.month,
+        &mut v.date,
+        &mut v.hour,
+        &mut v.minute,
+        &mut v.second,
+        &mut v.nanosecond,
+        date_time_diff.nanoseconds(),
+    )?;
+
+    Some(v)
+}
+
+#[cfg(feature = "chrono")]
+#[allow(clippy::too_many_arguments)]
+#[inline]
+fn to_date_time<Tz: TimeZone>(
+    timezone: &Tz,
+    year: i32,
+    month: i32,
+    date: i32,
+    hour: i32,
+    minute: i32,
+    second: i32,
+    nanosecond: i32,
+) -> LocalResult<DateTime<Tz>> {
+    match timezone.with_ymd_and_hms(
+        year,
+        month as u32 + 1,
+        date as u32,
+        hour as u32,
+        minute as u32,
+        second as u32,
+    ) {
+        LocalResult::Single(v) => {
+            match v.checked_add_signed(Duration::nanoseconds(nanosecond as i64)) {
+                Some(v) => LocalResult::Single(v),
+                None => LocalResult::None,
+            }
+        },
+        LocalResult::Ambiguous(a, b) => {
+            let delta = Duration::nanoseconds(nanosecond as i64);
+            LocalResult::Ambiguous(
+                match a.checked_add_signed(delta) {
+                    Some(v) => v,
+                    None => return LocalResult::None,
+                },
+                match b.checked_add_signed(delta) {
+                    Some(v) => v,
+                    None => return LocalResult::None,
+                },
+            )
+        },
+        LocalResult::None => LocalResult::None,
+    }
+}
+
+#[cfg(feature = "chrono")]
 /// Calculate `from` + `date_time_diff`.
 ///
+/// If the day of month does not exist after adding the years and the months, it is clamped to the last day of the month. Every component is added on the wall clock, as `TimeSemantics::WallClock`. Use `add_date_time_diff_with_options` for other policies.
+///
 /// # Example
 ///
 /// ```rust
@@ -199,114 +321,498 @@ fn nanosecond_add(
 ///     date_after_1_year_1_day
 /// )
 /// ```
+#[inline]
 pub fn add_date_time_diff<Tz: TimeZone>(
     from: DateTime<Tz>,
     date_time_diff: &dyn DateTimeDiff,
 ) -> LocalResult<DateTime<Tz>> {
-    let mut year = match from.year().checked_add(date_time_diff.years()) {
+    add_on_wall_clock(from, date_time_diff, EndOfMonth::Clamp)
+}
+
+#[cfg(feature = "chrono")]
+/// Calculate `from` + `date_time_diff` with an end-of-month policy and a time semantics. Return `LocalResult::None` if the policy is `EndOfMonth::Reject` and the day of month does not exist after adding the years and the months.
+///
+/// With `TimeSemantics::Hybrid`, the date-time after the years, the months and the days is resolved by `Disambiguation::Compatible` before the time is added, so the result is never ambiguous.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{
+///     add_date_time_diff_with_options, AddOptions, DateDiffResult, EndOfMonth,
+/// };
+///
+/// let date = Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap();
+///
+/// let one_month = DateDiffResult {
+///     months: 1,
+///     ..DateDiffResult::default()
+/// };
+///
+/// assert_eq!(
+///     Utc.with_ymd_and_hms(2021, 3, 3, 0, 0, 0).unwrap(),
+///     add_date_time_diff_with_options(date, &one_month, &AddOptions {
+///         end_of_month: EndOfMonth::Overflow,
+///         ..AddOptions::default()
+///     })
+///     .unwrap()
+/// );
+///
+/// let date = Utc.with_ymd_and_hms(2021, 2, 28, 0, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     Utc.with_ymd_and_hms(2021, 3, 31, 0, 0, 0).unwrap(),
+///     add_date_time_diff_with_options(date, &one_month, &AddOptions {
+///         end_of_month: EndOfMonth::StickToMonthEnd,
+///         ..AddOptions::default()
+///     })
+///     .unwrap()
+/// );
+/// ```
+pub fn add_date_time_diff_with_options<Tz: TimeZone>(
+    from: DateTime<Tz>,
+    date_time_diff: &dyn DateTimeDiff,
+    options: &AddOptions,
+) -> LocalResult<DateTime<Tz>> {
+    match options.time_semantics {
+        TimeSemantics::WallClock => add_on_wall_clock(from, date_time_diff, options.end_of_month),
+        TimeSemantics::Exact => {
+            let timezone = from.timezone();
+            let offset = from.offset().fix();
+
+            add_on_wall_clock(from.with_timezone(&offset), date_time_diff, options.end_of_month)
+                .map(|v| v.with_timezone(&timezone))
+        },
+        // the intermediate date-time is resolved as Temporal does
+        TimeSemantics::Hybrid => match add_date_time_diff_disambiguated(
+            from,
+            date_time_diff,
+            options,
+            Disambiguation::Compatible,
+        ) {
+            Ok(v) => LocalResult::Single(v),
+            Err(_) => LocalResult::None,
+        },
+    }
+}
+
+#[cfg(feature = "chrono")]
+fn add_on_wall_clock<Tz: TimeZone>(
+    from: DateTime<Tz>,
+    date_time_diff: &dyn DateTimeDiff,
+    end_of_month: EndOfMonth,
+) -> LocalResult<DateTime<Tz>> {
+    let wall_clock = WallClock {
+        year:       from.year(),
+        month:      from.month0() as i32,
+        date:       from.day() as i32,
+        hour:       from.hour() as i32,
+        minute:     from.minute() as i32,
+        second:     from.second() as i32,
+        nanosecond: from.nanosecond() as i32,
+    };
+
+    let v = match _add_wall_clock(wall_clock, date_time_diff, end_of_month) {
         Some(v) => v,
         None => return LocalResult::None,
     };
 
-    let mut month = from.month0() as i32;
+    to_date_time(
+        &from.timezone(),
+        v.year,
+        v.month,
+        v.date,
+        v.hour,
+        v.minute,
+        v.second,
+        v.nanosecond,
+    )
+}
 
-    if month_add(&mut year, &mut month, date_time_diff.months()).is_none() {
-        return LocalResult::None;
+// the time components as the exact elapsed time
+#[cfg(feature = "chrono")]
+#[inline]
+fn time_duration(date_time_diff: &dyn DateTimeDiff) -> Duration {
+    Duration::hours(date_time_diff.hours() as i64)
+        + Duration::minutes(date_time_diff.minutes() as i64)
+        + Duration::seconds(date_time_diff.seconds() as i64)
+        + Duration::nanoseconds(date_time_diff.nanoseconds() as i64)
+}
+
+#[cfg(feature = "chrono")]
+/// Errors that can occur when adding a difference to a `DateTime`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DiffError {
+    /// A component overflows an `i32` while being added, e.g. `i32::MAX` years are `i32::MAX * 12` months.
+    Overflow,
+    /// The result is out of the range of `chrono`.
+    OutOfChronoRange,
+    /// The day of month does not exist after adding the years and the months, and the end-of-month policy is `EndOfMonth::Reject`.
+    NonexistentDate,
+    /// The local date-time falls into a gap of the time zone, and the disambiguation is `Disambiguation::Reject`.
+    NonexistentLocalTime,
+    /// The local date-time falls into a fold of the time zone, and the disambiguation is `Disambiguation::Reject`.
+    AmbiguousLocalTime,
+}
+
+#[cfg(feature = "chrono")]
+impl Display for DiffError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DiffError::Overflow => f.write_str("date-time component overflow"),
+            DiffError::OutOfChronoRange => f.write_str("date-time out of range"),
+            DiffError::NonexistentDate => f.write_str("day of month does not exist"),
+            DiffError::NonexistentLocalTime => f.write_str("local date-time does not exist"),
+            DiffError::AmbiguousLocalTime => f.write_str("local date-time is ambiguous"),
+        }
     }
+}
 
-    let mut date = from.day() as i32;
+// `core::error::Error` needs Rust 1.81
+#[cfg(all(feature = "chrono", feature = "std"))]
+impl std::error::Error for DiffError {}
 
-    let days_in_month = year_helper::get_days_in_month(year, (month + 1) as u8).unwrap() as i32;
+// the wall clock of `from` + `date_time_diff`
+#[cfg(feature = "chrono")]
+fn add_on_naive_wall_clock<Tz: TimeZone>(
+    from: &DateTime<Tz>,
+    date_time_diff: &dyn DateTimeDiff,
+    end_of_month: EndOfMonth,
+) -> Result<NaiveDateTime, DiffError> {
+    let local = from.naive_local();
+
+    let wall_clock = WallClock {
+        year:       local.year(),
+        month:      local.month0() as i32,
+        date:       local.day() as i32,
+        hour:       local.hour() as i32,
+        minute:     local.minute() as i32,
+        second:     local.second() as i32,
+        nanosecond: local.nanosecond() as i32,
+    };
+
+    let v = match _add_wall_clock(wall_clock, date_time_diff, end_of_month) {
+        Some(v) => v,
+        // clamping only fails on an overflow
+        None if end_of_month == EndOfMonth::Reject
+            && _add_wall_clock(wall_clock, date_time_diff, EndOfMonth::Clamp).is_some() =>
+        {
+            return Err(DiffError::NonexistentDate)
+        },
+        None => return Err(DiffError::Overflow),
+    };
+
+    NaiveDate::from_ymd_opt(v.year, v.month as u32 + 1, v.date as u32)
+        .and_then(|date| {
+            date.and_hms_nano_opt(
+                v.hour as u32,
+                v.minute as u32,
+                v.second as u32,
+                v.nanosecond as u32,
+            )
+        })
+        .ok_or(DiffError::OutOfChronoRange)
+}
 
-    if date > days_in_month {
-        date = days_in_month;
+// the only resolver of a wall-clock date-time in a gap or a fold of a time zone, where a date-time in a gap is moved by the length of the gap, forward with the offset before the gap (`Compatible` and `Later`) and backward with the offset after it (`Earlier`)
+#[cfg(feature = "chrono")]
+pub(crate) fn _disambiguate<Tz: TimeZone>(
+    timezone: &Tz,
+    local: &NaiveDateTime,
+    disambiguation: Disambiguation,
+) -> Result<DateTime<Tz>, DiffError> {
+    match timezone.from_local_datetime(local) {
+        LocalResult::Single(v) => Ok(v),
+        LocalResult::Ambiguous(earlier, later) => match disambiguation {
+            Disambiguation::Compatible | Disambiguation::Earlier => Ok(earlier),
+            Disambiguation::Later => Ok(later),
+            Disambiguation::Reject => Err(DiffError::AmbiguousLocalTime),
+        },
+        LocalResult::None => {
+            // the offsets a day before and after the gap, since no gap is longer than a day
+            let offset_at = |days: i64| {
+                local
+                    .checked_add_signed(Duration::days(days))
+                    .map(|v| timezone.offset_from_utc_datetime(&v).fix().local_minus_utc())
+                    .ok_or(DiffError::OutOfChronoRange)
+            };
+
+            // with the offset before the gap, the local date-time is moved forward by the length of the gap, and vice versa
+            let offset = match disambiguation {
+                Disambiguation::Compatible | Disambiguation::Later => offset_at(-1)?,
+                Disambiguation::Earlier => offset_at(1)?,
+                Disambiguation::Reject => return Err(DiffError::NonexistentLocalTime),
+            };
+
+            let utc = local
+                .checked_sub_signed(Duration::seconds(offset as i64))
+                .ok_or(DiffError::OutOfChronoRange)?;
+
+            Ok(timezone.from_utc_datetime(&utc))
+        },
     }
+}
 
-    if date_add(&mut year, &mut month, &mut date, date_time_diff.days()).is_none() {
-        return LocalResult::None;
+#[cfg(feature = "chrono")]
+/// Calculate `from` + `date_time_diff` with `options`, and resolve a local date-time in a gap or a fold of the time zone by `disambiguation` instead of returning a `LocalResult`.
+///
+/// With `TimeSemantics::Hybrid`, the date-time after the years, the months and the days is resolved before the time is added, and `from` itself is kept if they are all zero. With `TimeSemantics::Exact`, there is nothing to resolve.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{
+///     add_date_time_diff_disambiguated, AddOptions, DateDiffResult,
+///     DiffError, Disambiguation, EndOfMonth,
+/// };
+///
+/// let from = Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap();
+///
+/// let one_month = DateDiffResult {
+///     months: 1,
+///     ..DateDiffResult::default()
+/// };
+///
+/// assert_eq!(
+///     Ok(Utc.with_ymd_and_hms(2021, 2, 28, 0, 0, 0).unwrap()),
+///     add_date_time_diff_disambiguated(
+///         from,
+///         &one_month,
+///         &AddOptions::default(),
+///         Disambiguation::Compatible
+///     )
+/// );
+///
+/// assert_eq!(
+///     Err(DiffError::NonexistentDate),
+///     add_date_time_diff_disambiguated(
+///         from,
+///         &one_month,
+///         &AddOptions {
+///             end_of_month: EndOfMonth::Reject,
+///             ..AddOptions::default()
+///         },
+///         Disambiguation::Compatible
+///     )
+/// );
+/// ```
+pub fn add_date_time_diff_disambiguated<Tz: TimeZone>(
+    from: DateTime<Tz>,
+    date_time_diff: &dyn DateTimeDiff,
+    options: &AddOptions,
+    disambiguation: Disambiguation,
+) -> Result<DateTime<Tz>, DiffError> {
+    let timezone = from.timezone();
+
+    match options.time_semantics {
+        TimeSemantics::WallClock => {
+            let local = add_on_naive_wall_clock(&from, date_time_diff, options.end_of_month)?;
+
+            _disambiguate(&timezone, &local, disambiguation)
+        },
+        TimeSemantics::Exact => {
+            let from = from.fixed_offset();
+
+            let local = add_on_naive_wall_clock(&from, date_time_diff, options.end_of_month)?;
+
+            let utc = local
+                .checked_sub_signed(Duration::seconds(from.offset().local_minus_utc() as i64))
+                .ok_or(DiffError::OutOfChronoRange)?;
+
+            Ok(timezone.from_utc_datetime(&utc))
+        },
+        TimeSemantics::Hybrid => {
+            let date_diff = DateDiffResult {
+                years:  date_time_diff.years(),
+                months: date_time_diff.months(),
+                days:   total_days(date_time_diff).ok_or(DiffError::Overflow)?,
+            };
+
+            // `from` is not resolved again if it does not move on the wall clock, e.g. in a fold
+            let intermediate = if date_diff == DateDiffResult::default() {
+                from
+            } else {
+                let local = add_on_naive_wall_clock(&from, &date_diff, options.end_of_month)?;
+
+                _disambiguate(&timezone, &local, disambiguation)?
+            };
+
+            intermediate
+                .checked_add_signed(time_duration(date_time_diff))
+                .ok_or(DiffError::OutOfChronoRange)
+        },
     }
+}
 
-    let mut hour = from.hour() as i32;
+#[cfg(feature = "chrono")]
+/// Calculate `from` + `date_time_diff` like `add_date_time_diff`, but return a `DiffError` which tells why there is no single result, e.g. `DiffError::NonexistentLocalTime` for a local date-time in a gap of the time zone and `DiffError::AmbiguousLocalTime` for one in a fold.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{try_add_date_time_diff, DateDiffResult, DiffError};
+///
+/// let date = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     Ok(Utc.with_ymd_and_hms(2001, 1, 2, 0, 0, 0).unwrap()),
+///     try_add_date_time_diff(date, &DateDiffResult {
+///         years: 1,
+///         days: 1,
+///         ..DateDiffResult::default()
+///     })
+/// );
+///
+/// assert_eq!(
+///     Err(DiffError::Overflow),
+///     try_add_date_time_diff(date, &DateDiffResult {
+///         years: i32::MAX,
+///         ..DateDiffResult::default()
+///     })
+/// );
+///
+/// assert_eq!(
+///     Err(DiffError::OutOfChronoRange),
+///     try_add_date_time_diff(date, &DateDiffResult {
+///         years: 1000000,
+///         ..DateDiffResult::default()
+///     })
+/// );
+/// ```
+#[inline]
+pub fn try_add_date_time_diff<Tz: TimeZone>(
+    from: DateTime<Tz>,
+    date_time_diff: &dyn DateTimeDiff,
+) -> Result<DateTime<Tz>, DiffError> {
+    add_date_time_diff_disambiguated(
+        from,
+        date_time_diff,
+        &AddOptions {
+            time_semantics: TimeSemantics::WallClock,
+            ..AddOptions::default()
+        },
+        Disambiguation::Reject,
+    )
+}
 
-    if hour_add(&mut year, &mut month, &mut date, &mut hour, date_time_diff.hours()).is_none() {
-        return LocalResult::None;
+#[cfg(feature = "chrono")]
+/// Calculate `from` - `date_time_diff`, walking backwards in the reverse order of `add_date_time_diff`.
+///
+/// `add_date_time_diff` adds the years and months first (clamping the day of month), then the days, then the time, all on the wall clock. This function undoes the time first, then the days, and the years and months last.
+///
+/// `sub_date_time_diff(b, &date_time_diff(a, b))` is `a`, or an ambiguous result which contains `a` if the wall clock of `a` is in a fold of the time zone, as long as `a` is the only date-time with that difference to `b`. Because of the clamping of the day of month, up to 4 dates can have the same difference to `b`, e.g. `date_time_diff` from 2020-01-29, 2020-01-30 and 2020-01-31 to 2020-03-01 are all 1 month 1 day, and `b` and the difference cannot tell them apart. In such a case, the earliest of them (2020-01-29) is returned. What always holds is `date_time_diff(sub_date_time_diff(b, &diff), b) == diff` for `diff = date_time_diff(a, b)`.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{date_time_diff, sub_date_time_diff};
+///
+/// let a = Local.with_ymd_and_hms(2020, 2, 27, 0, 0, 0).unwrap();
+/// let b = Local.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap();
+///
+/// assert_eq!(a, sub_date_time_diff(b, &date_time_diff(a, b)).unwrap());
+/// assert_eq!(b, sub_date_time_diff(a, &date_time_diff(b, a)).unwrap());
+/// ```
+pub fn sub_date_time_diff<Tz: TimeZone>(
+    from: DateTime<Tz>,
+    date_time_diff: &dyn DateTimeDiff,
+) -> LocalResult<DateTime<Tz>> {
+    // the time is undone on the wall clock, as `date_time_diff` counts it
+    let intermediate = match from.naive_local().checked_sub_signed(time_duration(date_time_diff)) {
+        Some(v) => v,
+        None => return LocalResult::None,
+    };
+
+    let mut year = intermediate.year();
+    let mut month = intermediate.month0() as i32;
+    let mut date = intermediate.day() as i32;
+    let hour = intermediate.hour() as i32;
+    let minute = intermediate.minute() as i32;
+    let second = intermediate.second() as i32;
+    let nanosecond = intermediate.nanosecond() as i32;
+
+    macro_rules! neg {
+        ($v:expr) => {
+            match $v.checked_neg() {
+                Some(v) => v,
+                None => return LocalResult::None,
+            }
+        };
     }
 
-    let mut minute = from.minute() as i32;
+    let days = match total_days(date_time_diff) {
+        Some(v) => v,
+        None => return LocalResult::None,
+    };
 
-    if minute_add(
-        &mut year,
-        &mut month,
-        &mut date,
-        &mut hour,
-        &mut minute,
-        date_time_diff.minutes(),
-    )
-    .is_none()
-    {
+    if date_add(&mut year, &mut month, &mut date, neg!(days)).is_none() {
         return LocalResult::None;
     }
 
-    let mut second = from.second() as i32;
+    let intermediate_days_in_month =
+        year_helper::get_days_in_month(year, (month + 1) as u8).unwrap() as i32;
 
-    if second_add(
-        &mut year,
-        &mut month,
-        &mut date,
-        &mut hour,
-        &mut minute,
-        &mut second,
-        date_time_diff.seconds(),
-    )
-    .is_none()
-    {
+    year = match year.checked_sub(date_time_diff.years()) {
+        Some(v) => v,
+        None => return LocalResult::None,
+    };
+
+    if month_add(&mut year, &mut month, neg!(date_time_diff.months())).is_none() {
         return LocalResult::None;
     }
 
-    let mut nanosecond = from.nanosecond() as i32;
+    let days_in_month = year_helper::get_days_in_month(year, (month + 1) as u8).unwrap() as i32;
 
-    if nanosecond_add(
-        &mut year,
-        &mut month,
-        &mut date,
-        &mut hour,
-        &mut minute,
-        &mut second,
-        &mut nanosecond,
-        date_time_diff.nanoseconds(),
-    )
-    .is_none()
-    {
-        return LocalResult::None;
+    if date >= days_in_month {
+        return to_date_time(
+            &from.timezone(),
+            year,
+            month,
+            days_in_month,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        );
     }
 
-    match from.timezone().with_ymd_and_hms(
-        year,
-        month as u32 + 1,
-        date as u32,
-        hour as u32,
-        minute as u32,
-        second as u32,
-    ) {
-        LocalResult::Single(v) => {
-            match v.checked_add_signed(Duration::nanoseconds(nanosecond as i64)) {
-                Some(v) => LocalResult::Single(v),
-                None => LocalResult::None,
+    if date < intermediate_days_in_month {
+        return to_date_time(&from.timezone(), year, month, date, hour, minute, second, nanosecond);
+    }
+
+    // the intermediate date is the last day of a month which is shorter than the result month, so it may be clamped by `add_date_time_diff`, e.g. 2020-01-29, 2020-01-30 and 2020-01-31 + 1 month are all 2020-02-29
+    // find the earliest date which gives `date_time_diff` back
+    for candidate_date in date..=days_in_month {
+        let candidate = to_date_time(
+            &from.timezone(),
+            year,
+            month,
+            candidate_date,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        );
+
+        if let Some(v) = candidate.clone().earliest() {
+            let diff = super::date_time_diff(v, from.clone());
+
+            if diff.years == date_time_diff.years()
+                && diff.months == date_time_diff.months()
+                && diff.days == days
+                && diff.hours == date_time_diff.hours()
+                && diff.minutes == date_time_diff.minutes()
+                && diff.seconds == date_time_diff.seconds()
+                && diff.nanoseconds == date_time_diff.nanoseconds()
+            {
+                return candidate;
             }
-        },
-        LocalResult::Ambiguous(a, b) => {
-            let delta = Duration::nanoseconds(nanosecond as i64);
-            LocalResult::Ambiguous(
-                match a.checked_add_signed(delta) {
-                    Some(v) => v,
-                    None => return LocalResult::None,
-                },
-                match b.checked_add_signed(delta) {
-                    Some(v) => v,
-                    None => return LocalResult::None,
-                },
-            )
-        },
-        LocalResult::None => LocalResult::None,
+        }
     }
+
+    to_date_time(&from.timezone(), year, month, date, hour, minute, second, nanosecond)
 }
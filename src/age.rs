@@ -0,0 +1,61 @@
+use chrono::prelude::*;
+
+use super::{date_only_diff, diff::_date_diff_with_end_of_month, DateDiffResult, EndOfMonth};
+
+/// Which day is the birthday of a person born on February 29 in a common year.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LeapDayRule {
+    /// The birthday is February 28.
+    Feb28,
+    /// The birthday is March 1. `date_diff` counts the years by this rule.
+    Mar1,
+}
+
+/// The age of a person on a date.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+pub struct Age {
+    /// The number of birthdays passed, including the one on the date, i.e. the age in years.
+    pub completed_years: i32,
+    /// The difference from the birth date. Its `years` is the same as `completed_years`.
+    pub diff:            DateDiffResult,
+}
+
+/// Calculate the age of a person born on `birth` on the date `on`. The times of day are ignored. Return `None` if `on` is before `birth`.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{age, LeapDayRule};
+///
+/// let birth = NaiveDate::from_ymd_opt(2004, 2, 29).unwrap();
+/// let on = NaiveDate::from_ymd_opt(2022, 2, 28).unwrap();
+///
+/// assert_eq!(18, age(birth, on, LeapDayRule::Feb28).unwrap().completed_years);
+/// assert_eq!(17, age(birth, on, LeapDayRule::Mar1).unwrap().completed_years);
+/// ```
+pub fn age<D: Datelike>(birth: D, on: D, leap_day_rule: LeapDayRule) -> Option<Age> {
+    let birth = NaiveDate::from_ymd_opt(birth.year(), birth.month(), birth.day())?;
+    let on = NaiveDate::from_ymd_opt(on.year(), on.month(), on.day())?;
+
+    if on < birth {
+        return None;
+    }
+
+    let diff = if birth.month() == 2 && birth.day() == 29 {
+        // Feb 29 + n years is Feb 28 by clamping or Mar 1 by overflowing in a common year, and other months have the 29th
+        let end_of_month = match leap_day_rule {
+            LeapDayRule::Feb28 => EndOfMonth::Clamp,
+            LeapDayRule::Mar1 => EndOfMonth::Overflow,
+        };
+
+        _date_diff_with_end_of_month(&birth, &on, 0, 0, false, end_of_month)?
+    } else {
+        date_only_diff(birth, on)
+    };
+
+    Some(Age {
+        completed_years: diff.years,
+        diff,
+    })
+}
@@ -0,0 +1,227 @@
+use chrono::{prelude::*, LocalResult};
+
+use super::{
+    add_date_time_diff_with_options, date_time_diff, AddOptions, DateDiffResult, DateTimeDiffResult,
+};
+
+#[inline]
+fn years_diff(years: i32) -> DateDiffResult {
+    DateDiffResult {
+        years,
+        ..DateDiffResult::default()
+    }
+}
+
+// the `years`-th anniversary on the wall clock, to be compared without time zone transitions
+#[inline]
+fn wall_clock_anniversary(
+    origin: &NaiveDateTime,
+    years: i32,
+    options: &AddOptions,
+) -> Option<NaiveDateTime> {
+    add_date_time_diff_with_options(Utc.from_utc_datetime(origin), &years_diff(years), options)
+        .single()
+        .map(|v| v.naive_utc())
+}
+
+#[inline]
+fn resolve<Tz: TimeZone>(
+    origin: DateTime<Tz>,
+    years: i32,
+    date_time: DateTime<Tz>,
+    options: &AddOptions,
+    anniversary_first: bool,
+) -> LocalResult<(DateTime<Tz>, DateTimeDiffResult)> {
+    add_date_time_diff_with_options(origin, &years_diff(years), options).map(|anniversary| {
+        let diff = if anniversary_first {
+            date_time_diff(anniversary.clone(), date_time.clone())
+        } else {
+            date_time_diff(date_time.clone(), anniversary.clone())
+        };
+
+        (anniversary, diff)
+    })
+}
+
+/// Find the first anniversary of `origin` (`origin` + n years) after `after`, and the difference from `after` to it.
+///
+/// If the day of month does not exist in a year, e.g. February 29, it is clamped. Use `next_anniversary_with_options` for other policies.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{next_anniversary, DateTimeDiffResult};
+///
+/// let origin = Utc.with_ymd_and_hms(2020, 2, 29, 9, 0, 0).unwrap();
+/// let after = Utc.with_ymd_and_hms(2022, 12, 25, 0, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     (
+///         Utc.with_ymd_and_hms(2023, 2, 28, 9, 0, 0).unwrap(),
+///         DateTimeDiffResult {
+///             months: 2,
+///             days: 3,
+///             hours: 9,
+///             ..DateTimeDiffResult::default()
+///         }
+///     ),
+///     next_anniversary(origin, after).unwrap()
+/// );
+/// ```
+#[inline]
+pub fn next_anniversary<Tz: TimeZone>(
+    origin: DateTime<Tz>,
+    after: DateTime<Tz>,
+) -> LocalResult<(DateTime<Tz>, DateTimeDiffResult)> {
+    next_anniversary_with_options(origin, after, &AddOptions::default())
+}
+
+/// Find the first anniversary of `origin` (`origin` + n years) after `after` with an end-of-month policy, and the difference from `after` to it.
+///
+/// The anniversaries are compared on the wall clock. Return `LocalResult::None` if the anniversary does not exist in the time zone or the years overflow.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{
+///     next_anniversary_with_options, AddOptions, EndOfMonth,
+/// };
+///
+/// let origin = Utc.with_ymd_and_hms(2020, 2, 29, 0, 0, 0).unwrap();
+/// let after = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+///
+/// let (anniversary, _) =
+///     next_anniversary_with_options(origin, after, &AddOptions {
+///         end_of_month: EndOfMonth::Overflow,
+///         ..AddOptions::default()
+///     })
+///     .unwrap();
+///
+/// assert_eq!(Utc.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap(), anniversary);
+///
+/// let (anniversary, _) =
+///     next_anniversary_with_options(origin, after, &AddOptions {
+///         end_of_month: EndOfMonth::Reject,
+///         ..AddOptions::default()
+///     })
+///     .unwrap();
+///
+/// assert_eq!(
+///     Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap(),
+///     anniversary
+/// );
+/// ```
+pub fn next_anniversary_with_options<Tz: TimeZone>(
+    origin: DateTime<Tz>,
+    after: DateTime<Tz>,
+    options: &AddOptions,
+) -> LocalResult<(DateTime<Tz>, DateTimeDiffResult)> {
+    let origin_wall_clock = origin.naive_local();
+    let after_wall_clock = after.naive_local();
+
+    // one year before the truncated difference is never after `after`
+    let mut years = date_time_diff(origin_wall_clock, after_wall_clock).years - 1;
+
+    // the anniversaries can be missing only for a few years in a row with `EndOfMonth::Reject`, e.g. February 29 from 1896 to 1904
+    let mut missing_years = 0;
+
+    loop {
+        match wall_clock_anniversary(&origin_wall_clock, years, options) {
+            Some(anniversary) => {
+                if anniversary > after_wall_clock {
+                    return resolve(origin, years, after, options, false);
+                }
+
+                missing_years = 0;
+            },
+            None => {
+                missing_years += 1;
+
+                if missing_years > 8 {
+                    return LocalResult::None;
+                }
+            },
+        }
+
+        years = match years.checked_add(1) {
+            Some(v) => v,
+            None => return LocalResult::None,
+        };
+    }
+}
+
+/// Find the last anniversary of `origin` (`origin` + n years) before `before`, and the difference from it to `before`.
+///
+/// If the day of month does not exist in a year, e.g. February 29, it is clamped. Use `previous_anniversary_with_options` for other policies.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{previous_anniversary, DateTimeDiffResult};
+///
+/// let origin = Utc.with_ymd_and_hms(2015, 6, 1, 0, 0, 0).unwrap();
+/// let before = Utc.with_ymd_and_hms(2022, 6, 1, 0, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     (
+///         Utc.with_ymd_and_hms(2021, 6, 1, 0, 0, 0).unwrap(),
+///         DateTimeDiffResult {
+///             years: 1,
+///             ..DateTimeDiffResult::default()
+///         }
+///     ),
+///     previous_anniversary(origin, before).unwrap()
+/// );
+/// ```
+#[inline]
+pub fn previous_anniversary<Tz: TimeZone>(
+    origin: DateTime<Tz>,
+    before: DateTime<Tz>,
+) -> LocalResult<(DateTime<Tz>, DateTimeDiffResult)> {
+    previous_anniversary_with_options(origin, before, &AddOptions::default())
+}
+
+/// Find the last anniversary of `origin` (`origin` + n years) before `before` with an end-of-month policy, and the difference from it to `before`.
+///
+/// The anniversaries are compared on the wall clock. Return `LocalResult::None` if the anniversary does not exist in the time zone or the years overflow.
+pub fn previous_anniversary_with_options<Tz: TimeZone>(
+    origin: DateTime<Tz>,
+    before: DateTime<Tz>,
+    options: &AddOptions,
+) -> LocalResult<(DateTime<Tz>, DateTimeDiffResult)> {
+    let origin_wall_clock = origin.naive_local();
+    let before_wall_clock = before.naive_local();
+
+    // one year after the truncated difference is never before `before`
+    let mut years = date_time_diff(origin_wall_clock, before_wall_clock).years + 1;
+
+    // the anniversaries can be missing only for a few years in a row with `EndOfMonth::Reject`, e.g. February 29 from 1896 to 1904
+    let mut missing_years = 0;
+
+    loop {
+        match wall_clock_anniversary(&origin_wall_clock, years, options) {
+            Some(anniversary) => {
+                if anniversary < before_wall_clock {
+                    return resolve(origin, years, before, options, true);
+                }
+
+                missing_years = 0;
+            },
+            None => {
+                missing_years += 1;
+
+                if missing_years > 8 {
+                    return LocalResult::None;
+                }
+            },
+        }
+
+        years = match years.checked_sub(1) {
+            Some(v) => v,
+            None => return LocalResult::None,
+        };
+    }
+}
@@ -0,0 +1,241 @@
+use chrono::{prelude::*, LocalResult};
+
+use super::{add_date_time_diff, DateDiffResult};
+
+/// A calendar of business days, i.e. the days which are neither weekend days nor holidays.
+pub trait BusinessCalendar {
+    /// Whether `weekday` is a weekend day.
+    fn is_weekend(&self, weekday: Weekday) -> bool;
+
+    /// Count the holidays in the range (`from`, `to`] which are not weekend days.
+    ///
+    /// This is called once for a range rather than once for a day, so it should be implemented by something like a binary search in a sorted list.
+    fn count_holidays(&self, from: NaiveDate, to: NaiveDate) -> u32;
+
+    /// Whether `date` is a business day.
+    #[inline]
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        if self.is_weekend(date.weekday()) {
+            return false;
+        }
+
+        match date.pred_opt() {
+            Some(previous) => self.count_holidays(previous, date) == 0,
+            None => true,
+        }
+    }
+}
+
+/// A `BusinessCalendar` with a weekend mask and a sorted list of holidays.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct HolidayCalendar<'a> {
+    /// Whether each weekday is a weekend day, indexed by `Weekday::num_days_from_monday`. The default value is Saturday and Sunday.
+    pub weekend:  [bool; 7],
+    /// The holidays in ascending order. The duplicated ones are counted once. The default value is empty.
+    pub holidays: &'a [NaiveDate],
+}
+
+impl<'a> Default for HolidayCalendar<'a> {
+    #[inline]
+    fn default() -> Self {
+        HolidayCalendar {
+            weekend: [false, false, false, false, false, true, true], holidays: &[]
+        }
+    }
+}
+
+impl<'a> BusinessCalendar for HolidayCalendar<'a> {
+    #[inline]
+    fn is_weekend(&self, weekday: Weekday) -> bool {
+        self.weekend[weekday.num_days_from_monday() as usize]
+    }
+
+    fn count_holidays(&self, from: NaiveDate, to: NaiveDate) -> u32 {
+        let start = self.holidays.partition_point(|v| *v <= from);
+        let end = self.holidays.partition_point(|v| *v <= to);
+
+        let mut count = 0;
+        let mut previous = None;
+
+        for holiday in self.holidays.get(start..end).unwrap_or(&[]) {
+            if previous != Some(*holiday) && !self.is_weekend(holiday.weekday()) {
+                count += 1;
+            }
+
+            previous = Some(*holiday);
+        }
+
+        count
+    }
+}
+
+// 0001-01-01 is Monday and its day number is 1
+#[inline]
+fn weekday_of(days: i64) -> Weekday {
+    match (days - 1).rem_euclid(7) {
+        0 => Weekday::Mon,
+        1 => Weekday::Tue,
+        2 => Weekday::Wed,
+        3 => Weekday::Thu,
+        4 => Weekday::Fri,
+        5 => Weekday::Sat,
+        _ => Weekday::Sun,
+    }
+}
+
+#[inline]
+fn date_of(days: i64) -> Option<NaiveDate> {
+    NaiveDate::from_num_days_from_ce_opt(i32::try_from(days).ok()?)
+}
+
+#[inline]
+fn weekdays_per_week(calendar: &(impl BusinessCalendar + ?Sized)) -> i64 {
+    (0..7).filter(|i| !calendar.is_weekend(weekday_of(*i))).count() as i64
+}
+
+// the number of the days which are not weekend days in (from, to], where from <= to
+fn count_weekdays(calendar: &(impl BusinessCalendar + ?Sized), from: i64, to: i64) -> i64 {
+    let days = to - from;
+
+    // the weekdays repeat every week, so only the days after the whole weeks are checked one by one
+    (days / 7) * weekdays_per_week(calendar)
+        + (1..=days % 7).filter(|i| !calendar.is_weekend(weekday_of(from + i))).count() as i64
+}
+
+// the day number of the `n`-th day which is not a weekend day after `from` (before `from` if `n` is negative), where n != 0
+fn skip_weekdays(calendar: &(impl BusinessCalendar + ?Sized), from: i64, n: i64) -> Option<i64> {
+    let per_week = weekdays_per_week(calendar);
+
+    if per_week == 0 {
+        return None;
+    }
+
+    let step = n.signum();
+
+    // jump over the whole weeks, and leave 1 to `per_week` days to be checked one by one
+    let weeks = (n.abs() - 1) / per_week;
+    let mut remaining = n.abs() - weeks * per_week;
+    let mut days = from.checked_add(weeks.checked_mul(7)?.checked_mul(step)?)?;
+
+    while remaining > 0 {
+        days = days.checked_add(step)?;
+
+        if !calendar.is_weekend(weekday_of(days)) {
+            remaining -= 1;
+        }
+    }
+
+    Some(days)
+}
+
+#[inline]
+fn count_holidays(calendar: &(impl BusinessCalendar + ?Sized), from: i64, to: i64) -> Option<i64> {
+    Some(calendar.count_holidays(date_of(from)?, date_of(to)?) as i64)
+}
+
+/// Calculate the number of business days after `from` until `to` (inclusive), i.e. the business days in the range (`from`, `to`]. If `to` is earlier than `from`, the result is the negative number of the business days in (`to`, `from`].
+///
+/// Only the dates are used. The whole weeks are counted at once and the holidays are counted by `BusinessCalendar::count_holidays`, so the days are never iterated one by one.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{business_days_between, HolidayCalendar};
+///
+/// let holidays = [NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()];
+/// let calendar = HolidayCalendar {
+///     holidays: &holidays,
+///     ..HolidayCalendar::default()
+/// };
+///
+/// // Friday to Friday, skipping Christmas on Wednesday
+/// let from = NaiveDate::from_ymd_opt(2024, 12, 20).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2024, 12, 27).unwrap();
+///
+/// assert_eq!(4, business_days_between(from, to, &calendar));
+/// assert_eq!(-4, business_days_between(to, from, &calendar));
+/// ```
+pub fn business_days_between<D: Datelike>(
+    from: D,
+    to: D,
+    calendar: &(impl BusinessCalendar + ?Sized),
+) -> i32 {
+    let from = from.num_days_from_ce() as i64;
+    let to = to.num_days_from_ce() as i64;
+
+    let (earlier, later, sign) = if from <= to { (from, to, 1) } else { (to, from, -1) };
+
+    let weekdays = count_weekdays(calendar, earlier, later);
+
+    // the dates of a `Datelike` are always in the range of `NaiveDate`
+    let holidays = count_holidays(calendar, earlier, later).unwrap_or(0);
+
+    ((weekdays - holidays) * sign) as i32
+}
+
+/// Move `from` by `n` business days, i.e. to the `n`-th business day after `from` (before `from` if `n` is negative). The time of day is kept on the wall clock.
+///
+/// `from` itself does not have to be a business day. For a positive `n`, `business_days_between(from, add_business_days(from, n), calendar)` is `n`. Return `LocalResult::None` if the result overflows, does not exist in the time zone, or the calendar has no business days.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{add_business_days, HolidayCalendar};
+///
+/// let calendar = HolidayCalendar::default();
+///
+/// // Friday evening
+/// let from = Utc.with_ymd_and_hms(2024, 12, 20, 18, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     Utc.with_ymd_and_hms(2024, 12, 24, 18, 0, 0).unwrap(),
+///     add_business_days(from, 2, &calendar).unwrap()
+/// );
+/// assert_eq!(
+///     Utc.with_ymd_and_hms(2024, 12, 18, 18, 0, 0).unwrap(),
+///     add_business_days(from, -2, &calendar).unwrap()
+/// );
+/// ```
+pub fn add_business_days<Tz: TimeZone>(
+    from: DateTime<Tz>,
+    n: i32,
+    calendar: &(impl BusinessCalendar + ?Sized),
+) -> LocalResult<DateTime<Tz>> {
+    let start = from.naive_local().date().num_days_from_ce() as i64;
+
+    let mut days = start;
+    let mut remaining = n as i64;
+
+    // the holidays which are skipped in a jump are made up by the next jump, until there is none
+    while remaining != 0 {
+        let next = match skip_weekdays(calendar, days, remaining) {
+            Some(v) => v,
+            None => return LocalResult::None,
+        };
+
+        let holidays = if remaining > 0 {
+            count_holidays(calendar, days, next)
+        } else {
+            count_holidays(calendar, next - 1, days - 1)
+        };
+
+        remaining = match holidays {
+            Some(v) => v * remaining.signum(),
+            None => return LocalResult::None,
+        };
+
+        days = next;
+    }
+
+    let days = match i32::try_from(days - start) {
+        Ok(v) => v,
+        Err(_) => return LocalResult::None,
+    };
+
+    add_date_time_diff(from, &DateDiffResult {
+        days,
+        ..DateDiffResult::default()
+    })
+}
@@ -1,4 +1,7 @@
 pub(crate) const SECOND_NANOSECONDS: u64 = 1000000000;
 pub(crate) const MINUTE_NANOSECONDS: u64 = 60 * SECOND_NANOSECONDS;
 pub(crate) const HOUR_NANOSECONDS: u64 = 60 * MINUTE_NANOSECONDS;
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
 pub(crate) const DAY_NANOSECONDS: u64 = 24 * HOUR_NANOSECONDS;
+#[cfg(feature = "chrono")]
+pub(crate) const WEEK_NANOSECONDS: u64 = 7 * DAY_NANOSECONDS;
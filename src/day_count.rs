@@ -0,0 +1,210 @@
+use core::cmp::Ordering;
+
+use chrono::prelude::*;
+
+use super::Fraction;
+
+/// Day-count conventions to calculate accrued interest between two dates.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DayCount {
+    /// 30/360 US (NASD), with the end-of-month rule for February, i.e. the last day of February counts as the 30th.
+    Thirty360Us,
+    /// 30E/360 (Eurobond basis), i.e. the 31st counts as the 30th.
+    Thirty360E,
+    /// 30E/360 ISDA, i.e. the last day of a month counts as the 30th, except that the last day of February is kept if it is the `maturity` date.
+    Thirty360EIsda { maturity: Option<NaiveDate> },
+    /// ACT/360, i.e. the actual number of days over 360.
+    Act360,
+    /// ACT/365F, i.e. the actual number of days over 365.
+    Act365Fixed,
+    /// ACT/ACT ISDA, i.e. the days in leap years over 366 plus the days in other years over 365.
+    ActActIsda,
+    /// ACT/ACT ICMA, i.e. the actual number of days over the number of days in the coupon period from `period_start` to `period_end` times the number of coupons per year (`frequency`). `0` is treated as `1`.
+    ActActIcma { period_start: NaiveDate, period_end: NaiveDate, frequency: u32 },
+}
+
+#[inline]
+fn is_last_day_of_month(year: i32, month: u32, day: u32) -> bool {
+    day == year_helper::get_days_in_month(year, month as u8).unwrap() as u32
+}
+
+#[inline]
+fn is_last_day_of_february(year: i32, month: u32, day: u32) -> bool {
+    month == 2 && is_last_day_of_month(year, month, day)
+}
+
+#[inline]
+fn actual_days(earlier: &impl Datelike, later: &impl Datelike) -> i64 {
+    later.num_days_from_ce() as i64 - earlier.num_days_from_ce() as i64
+}
+
+#[inline]
+fn fraction(numerator: i128, denominator: i128) -> Fraction {
+    // the denominators are positive days and the numerators are far from `i128::MIN`
+    Fraction::new(numerator, denominator).unwrap()
+}
+
+fn thirty_360_days(earlier: &impl Datelike, later: &impl Datelike, convention: &DayCount) -> i64 {
+    let (year_1, month_1, mut day_1) = (earlier.year(), earlier.month(), earlier.day());
+    let (year_2, month_2, mut day_2) = (later.year(), later.month(), later.day());
+
+    match convention {
+        DayCount::Thirty360Us => {
+            if is_last_day_of_february(year_1, month_1, day_1) {
+                if is_last_day_of_february(year_2, month_2, day_2) {
+                    day_2 = 30;
+                }
+
+                day_1 = 30;
+            }
+
+            if day_2 == 31 && day_1 >= 30 {
+                day_2 = 30;
+            }
+
+            if day_1 == 31 {
+                day_1 = 30;
+            }
+        },
+        DayCount::Thirty360E => {
+            day_1 = day_1.min(30);
+            day_2 = day_2.min(30);
+        },
+        DayCount::Thirty360EIsda {
+            maturity,
+        } => {
+            if is_last_day_of_month(year_1, month_1, day_1) {
+                day_1 = 30;
+            }
+
+            let is_maturity = maturity.map_or(false, |maturity| {
+                maturity.year() == year_2 && maturity.ordinal() == later.ordinal()
+            });
+
+            if is_last_day_of_month(year_2, month_2, day_2) && !(is_maturity && month_2 == 2) {
+                day_2 = 30;
+            }
+        },
+        _ => unreachable!(),
+    }
+
+    360 * (year_2 as i64 - year_1 as i64)
+        + 30 * (month_2 as i64 - month_1 as i64)
+        + (day_2 as i64 - day_1 as i64)
+}
+
+/// Count the days between two dates under a day-count convention. The result is negative if `to` is before `from`. The times of day are ignored.
+///
+/// The 30/360 conventions count every month as 30 days, and the others count the actual days.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{day_count, DayCount};
+///
+/// let from = NaiveDate::from_ymd_opt(2007, 2, 28).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2007, 3, 31).unwrap();
+///
+/// assert_eq!(30, day_count(from, to, &DayCount::Thirty360Us));
+/// assert_eq!(32, day_count(from, to, &DayCount::Thirty360E));
+/// assert_eq!(31, day_count(from, to, &DayCount::Act360));
+/// ```
+#[inline]
+pub fn day_count<D: Datelike>(from: D, to: D, convention: &DayCount) -> i64 {
+    _day_count(&from, &to, convention)
+}
+
+fn _day_count<D: Datelike>(from: &D, to: &D, convention: &DayCount) -> i64 {
+    let (earlier, later, sign) = match actual_days(from, to).cmp(&0) {
+        Ordering::Greater => (from, to, 1),
+        Ordering::Less => (to, from, -1),
+        Ordering::Equal => return 0,
+    };
+
+    let days = match convention {
+        DayCount::Thirty360Us
+        | DayCount::Thirty360E
+        | DayCount::Thirty360EIsda {
+            ..
+        } => thirty_360_days(earlier, later, convention),
+        _ => actual_days(earlier, later),
+    };
+
+    days * sign
+}
+
+/// Calculate the year fraction between two dates under a day-count convention as an exact fraction. The result is negative if `to` is before `from`. The times of day are ignored.
+///
+/// Call `to_f64` on the result to get a floating-point number.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{year_fraction, DayCount, Fraction};
+///
+/// let from = NaiveDate::from_ymd_opt(2003, 11, 1).unwrap();
+/// let to = NaiveDate::from_ymd_opt(2004, 5, 1).unwrap();
+///
+/// // 61 days in 2003 and 121 days in 2004
+/// assert_eq!(
+///     Fraction::new(61 * 366 + 121 * 365, 365 * 366).unwrap(),
+///     year_fraction(from, to, &DayCount::ActActIsda)
+/// );
+///
+/// assert_eq!(
+///     0.5,
+///     year_fraction(from, to, &DayCount::ActActIcma {
+///         period_start: from,
+///         period_end:   to,
+///         frequency:    2,
+///     })
+///     .to_f64()
+/// );
+/// ```
+pub fn year_fraction<D: Datelike>(from: D, to: D, convention: &DayCount) -> Fraction {
+    let days = _day_count(&from, &to, convention) as i128;
+
+    match convention {
+        DayCount::Thirty360Us
+        | DayCount::Thirty360E
+        | DayCount::Thirty360EIsda {
+            ..
+        }
+        | DayCount::Act360 => fraction(days, 360),
+        DayCount::Act365Fixed => fraction(days, 365),
+        DayCount::ActActIsda => {
+            let (earlier, later, sign) = if days < 0 { (&to, &from, -1) } else { (&from, &to, 1) };
+
+            let (year_1, year_2) = (earlier.year(), later.year());
+
+            let days_in_year_1 = year_helper::get_days_in_year(year_1) as i128;
+            let days_in_year_2 = year_helper::get_days_in_year(year_2) as i128;
+
+            if year_1 == year_2 {
+                return fraction(days, days_in_year_1);
+            }
+
+            // the rest of the first year, the whole years between, and the start of the last year
+            let days_1 = days_in_year_1 - earlier.ordinal0() as i128;
+            let days_2 = later.ordinal0() as i128;
+
+            fraction(
+                sign * (days_1 * days_in_year_2
+                    + (year_2 as i128 - year_1 as i128 - 1) * days_in_year_1 * days_in_year_2
+                    + days_2 * days_in_year_1),
+                days_in_year_1 * days_in_year_2,
+            )
+        },
+        DayCount::ActActIcma {
+            period_start,
+            period_end,
+            frequency,
+        } => {
+            let period_days = actual_days(period_start, period_end).abs().max(1) as i128;
+
+            fraction(days, period_days * (*frequency).max(1) as i128)
+        },
+    }
+}
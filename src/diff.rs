@@ -1,11 +1,23 @@
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
 use core::cmp::Ordering;
 
-use chrono::prelude::*;
+#[cfg(feature = "chrono")]
+use chrono::{prelude::*, Duration};
 
+#[cfg(any(feature = "time", feature = "jiff"))]
+use super::add_diff::WallClock;
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
 use super::constants::*;
-
+#[cfg(feature = "chrono")]
+use super::{
+    add_date_time_diff_with_options,
+    add_diff::{_disambiguate, month_add_with_end_of_month},
+    AddOptions, DiffOptions, Disambiguation, EndOfMonth, RoundingMode, TimeSemantics, Unit,
+};
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
 #[derive(Debug)]
-struct TimeDiffResult {
+pub(crate) struct TimeDiffResult {
     pub(crate) hours:       i32,
     pub(crate) minutes:     i32,
     pub(crate) seconds:     i32,
@@ -14,6 +26,7 @@ struct TimeDiffResult {
 
 /// The result of the `date_diff` function.
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DateDiffResult {
     pub years:  i32,
     pub months: i32,
@@ -32,15 +45,9 @@ impl DateDiffResult {
     }
 }
 
-#[derive(Debug)]
-struct _DateDiffResult {
-    pub(crate) earlier_nanoseconds_of_day: u64,
-    pub(crate) later_nanoseconds_of_day:   u64,
-    pub(crate) result:                     DateDiffResult,
-}
-
 /// The result of the `date_time_diff` function.
 #[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DateTimeDiffResult {
     pub years:       i32,
     pub months:      i32,
@@ -67,6 +74,37 @@ impl DateTimeDiffResult {
     }
 }
 
+/// The result of the `date_time_weeks_diff` function. It is the same as `DateTimeDiffResult` but has the `weeks` component.
+#[derive(Debug, Default, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DateTimeWeeksDiffResult {
+    pub years:       i32,
+    pub months:      i32,
+    pub weeks:       i32,
+    pub days:        i32,
+    pub hours:       i32,
+    pub minutes:     i32,
+    pub seconds:     i32,
+    pub nanoseconds: i32,
+}
+
+impl DateTimeWeeksDiffResult {
+    #[doc(hidden)]
+    #[inline]
+    pub fn into_neg(mut self) -> Self {
+        self.years *= -1;
+        self.months *= -1;
+        self.weeks *= -1;
+        self.days *= -1;
+        self.hours *= -1;
+        self.minutes *= -1;
+        self.seconds *= -1;
+        self.nanoseconds *= -1;
+
+        self
+    }
+}
+
 impl From<DateDiffResult> for DateTimeDiffResult {
     #[inline]
     fn from(value: DateDiffResult) -> Self {
@@ -88,6 +126,22 @@ impl From<DateTimeDiffResult> for DateDiffResult {
     }
 }
 
+impl From<DateTimeDiffResult> for DateTimeWeeksDiffResult {
+    #[inline]
+    fn from(value: DateTimeDiffResult) -> Self {
+        DateTimeWeeksDiffResult {
+            years:       value.years,
+            months:      value.months,
+            weeks:       0,
+            days:        value.days,
+            hours:       value.hours,
+            minutes:     value.minutes,
+            seconds:     value.seconds,
+            nanoseconds: value.nanoseconds,
+        }
+    }
+}
+
 /// A trait to represent a date-time difference with multiple units.
 pub trait DateTimeDiff {
     #[inline]
@@ -100,6 +154,11 @@ pub trait DateTimeDiff {
         0
     }
 
+    #[inline]
+    fn weeks(&self) -> i32 {
+        0
+    }
+
     #[inline]
     fn days(&self) -> i32 {
         0
@@ -180,6 +239,49 @@ impl DateTimeDiff for DateTimeDiffResult {
     }
 }
 
+impl DateTimeDiff for DateTimeWeeksDiffResult {
+    #[inline]
+    fn years(&self) -> i32 {
+        self.years
+    }
+
+    #[inline]
+    fn months(&self) -> i32 {
+        self.months
+    }
+
+    #[inline]
+    fn weeks(&self) -> i32 {
+        self.weeks
+    }
+
+    #[inline]
+    fn days(&self) -> i32 {
+        self.days
+    }
+
+    #[inline]
+    fn hours(&self) -> i32 {
+        self.hours
+    }
+
+    #[inline]
+    fn minutes(&self) -> i32 {
+        self.minutes
+    }
+
+    #[inline]
+    fn seconds(&self) -> i32 {
+        self.seconds
+    }
+
+    #[inline]
+    fn nanoseconds(&self) -> i32 {
+        self.nanoseconds
+    }
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
 #[inline]
 const fn _nanoseconds_to_units(mut nanoseconds: u64) -> TimeDiffResult {
     let h = nanoseconds / HOUR_NANOSECONDS;
@@ -199,8 +301,57 @@ const fn _nanoseconds_to_units(mut nanoseconds: u64) -> TimeDiffResult {
     }
 }
 
+#[cfg(feature = "chrono")]
 #[inline]
-const fn _time_diff(
+pub(crate) fn _nanoseconds_to_units_up_to(
+    mut nanoseconds: i128,
+    largest_unit: Unit,
+) -> Option<DateTimeWeeksDiffResult> {
+    let mut result = DateTimeWeeksDiffResult::default();
+
+    if largest_unit >= Unit::Weeks {
+        let w = nanoseconds / WEEK_NANOSECONDS as i128;
+        nanoseconds -= w * WEEK_NANOSECONDS as i128;
+
+        result.weeks = i32::try_from(w).ok()?;
+    }
+
+    if largest_unit >= Unit::Days {
+        let d = nanoseconds / DAY_NANOSECONDS as i128;
+        nanoseconds -= d * DAY_NANOSECONDS as i128;
+
+        result.days = i32::try_from(d).ok()?;
+    }
+
+    if largest_unit >= Unit::Hours {
+        let h = nanoseconds / HOUR_NANOSECONDS as i128;
+        nanoseconds -= h * HOUR_NANOSECONDS as i128;
+
+        result.hours = i32::try_from(h).ok()?;
+    }
+
+    if largest_unit >= Unit::Minutes {
+        let m = nanoseconds / MINUTE_NANOSECONDS as i128;
+        nanoseconds -= m * MINUTE_NANOSECONDS as i128;
+
+        result.minutes = i32::try_from(m).ok()?;
+    }
+
+    if largest_unit >= Unit::Seconds {
+        let s = nanoseconds / SECOND_NANOSECONDS as i128;
+        nanoseconds -= s * SECOND_NANOSECONDS as i128;
+
+        result.seconds = i32::try_from(s).ok()?;
+    }
+
+    result.nanoseconds = i32::try_from(nanoseconds).ok()?;
+
+    Some(result)
+}
+
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+#[inline]
+pub(crate) const fn _time_diff(
     earlier_nanoseconds_of_day: u64,
     later_nanoseconds_of_day: u64,
 ) -> TimeDiffResult {
@@ -213,44 +364,116 @@ const fn _time_diff(
     _nanoseconds_to_units(nanoseconds)
 }
 
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
 #[inline]
-fn _date_time_nanoseconds_of_day(date_time: impl Timelike) -> u64 {
-    (date_time.hour() as u64 * HOUR_NANOSECONDS)
-        + (date_time.minute() as u64 * MINUTE_NANOSECONDS)
-        + (date_time.second() as u64 * SECOND_NANOSECONDS)
-        + date_time.nanosecond() as u64
+pub(crate) const fn _nanoseconds_of_day(
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+) -> u64 {
+    (hour as u64 * HOUR_NANOSECONDS)
+        + (minute as u64 * MINUTE_NANOSECONDS)
+        + (second as u64 * SECOND_NANOSECONDS)
+        + nanosecond as u64
 }
 
-#[inline]
-const fn _time_nanoseconds_of_day(timestamp: i64) -> u64 {
-    if timestamp >= 0 {
-        (timestamp as u64) % DAY_NANOSECONDS
-    } else {
-        let mut t = DAY_NANOSECONDS + ((-timestamp) as u64 % DAY_NANOSECONDS);
+// the difference between two instants by their dates and nanoseconds of day on the wall clock, where `earlier` is earlier than `later`
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+pub(crate) fn _date_and_nanoseconds_of_day_diff(
+    earlier: ((i32, u8, u8), u64),
+    later: ((i32, u8, u8), u64),
+    start_from_later: bool,
+) -> DateTimeDiffResult {
+    let ((earlier_date, earlier_nanoseconds_of_day), (later_date, later_nanoseconds_of_day)) =
+        (earlier, later);
 
-        if t == DAY_NANOSECONDS {
-            t = 0;
-        }
+    let date_diff = _date_diff(
+        earlier_date,
+        later_date,
+        earlier_nanoseconds_of_day,
+        later_nanoseconds_of_day,
+        start_from_later,
+    );
+
+    let time_diff = _time_diff(earlier_nanoseconds_of_day, later_nanoseconds_of_day);
+
+    let result = DateTimeDiffResult {
+        years:       date_diff.years,
+        months:      date_diff.months,
+        days:        date_diff.days,
+        hours:       time_diff.hours,
+        minutes:     time_diff.minutes,
+        seconds:     time_diff.seconds,
+        nanoseconds: time_diff.nanoseconds,
+    };
 
-        t
+    if start_from_later {
+        result.into_neg()
+    } else {
+        result
     }
 }
 
-fn _date_diff(
-    earlier: impl Datelike + Timelike,
-    later: impl Datelike + Timelike,
+#[cfg(any(feature = "time", feature = "jiff"))]
+#[inline]
+fn wall_clock_date_and_nanoseconds_of_day(v: &WallClock) -> ((i32, u8, u8), u64) {
+    (
+        (v.year, (v.month + 1) as u8, v.date as u8),
+        _nanoseconds_of_day(v.hour as u32, v.minute as u32, v.second as u32, v.nanosecond as u32),
+    )
+}
+
+#[cfg(any(feature = "time", feature = "jiff"))]
+#[inline]
+pub(crate) fn _wall_clock_diff(
+    earlier: &WallClock,
+    later: &WallClock,
     start_from_later: bool,
-) -> _DateDiffResult {
-    let mut earlier_year = earlier.year();
-    let mut earlier_month = earlier.month() as u8;
-    let mut earlier_date = earlier.day() as u8;
+) -> DateTimeDiffResult {
+    _date_and_nanoseconds_of_day_diff(
+        wall_clock_date_and_nanoseconds_of_day(earlier),
+        wall_clock_date_and_nanoseconds_of_day(later),
+        start_from_later,
+    )
+}
+
+#[cfg(feature = "chrono")]
+#[inline]
+pub(crate) fn _date_time_nanoseconds_of_day(date_time: &impl Timelike) -> u64 {
+    _nanoseconds_of_day(
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second(),
+        date_time.nanosecond(),
+    )
+}
+
+// the nanoseconds of day of a Unix timestamp in nanoseconds, which is also correct before 1970
+#[cfg(all(feature = "chrono", feature = "std"))]
+#[inline]
+pub(crate) const fn _time_nanoseconds_of_day(timestamp: i64) -> u64 {
+    timestamp.rem_euclid(DAY_NANOSECONDS as i64) as u64
+}
 
-    let mut later_year = later.year();
-    let mut later_month = later.month() as u8;
-    let mut later_date = later.day() as u8;
+// the year, the month and the day of month
+#[cfg(feature = "chrono")]
+#[inline]
+pub(crate) fn _ymd(date: &impl Datelike) -> (i32, u8, u8) {
+    (date.year(), date.month() as u8, date.day() as u8)
+}
 
-    let later_nanoseconds_of_day = _date_time_nanoseconds_of_day(later);
-    let earlier_nanoseconds_of_day = _date_time_nanoseconds_of_day(earlier);
+// `earlier` and `later` are the years, the months and the days of month
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
+pub(crate) fn _date_diff(
+    earlier: (i32, u8, u8),
+    later: (i32, u8, u8),
+    earlier_nanoseconds_of_day: u64,
+    later_nanoseconds_of_day: u64,
+    start_from_later: bool,
+) -> DateDiffResult {
+    let (mut earlier_year, mut earlier_month, mut earlier_date) = earlier;
+    let (mut later_year, mut later_month, mut later_date) = later;
 
     let years: i32;
     let months: i32;
@@ -391,17 +614,246 @@ fn _date_diff(
         }
     }
 
-    _DateDiffResult {
-        earlier_nanoseconds_of_day,
-        later_nanoseconds_of_day,
-        result: DateDiffResult {
-            years,
-            months,
-            days,
+    DateDiffResult {
+        years,
+        months,
+        days,
+    }
+}
+
+// count the months as many as possible under the end-of-month policy, and then the remaining days
+#[cfg(feature = "chrono")]
+pub(crate) fn _date_diff_with_end_of_month<D: Datelike>(
+    earlier: &D,
+    later: &D,
+    earlier_nanoseconds_of_day: u64,
+    later_nanoseconds_of_day: u64,
+    start_from_later: bool,
+    end_of_month: EndOfMonth,
+) -> Option<DateDiffResult> {
+    let (anchor, target, sign) =
+        if start_from_later { (later, earlier, -1) } else { (earlier, later, 1) };
+
+    // the months differ from the months between the two months only around the end of month, e.g. 2021-01-31 + 1 month is 2021-03-03 with `EndOfMonth::Overflow` and has no result with `EndOfMonth::Reject`
+    let months_between = i32::try_from(
+        ((target.year() as i64 - anchor.year() as i64) * 12 + target.month0() as i64
+            - anchor.month0() as i64)
+            * sign as i64,
+    )
+    .ok()?;
+
+    let target_days = target.num_days_from_ce() as i64;
+
+    // the time of day decides whether the intermediate date can be the same as the target date
+    let time_remains = later_nanoseconds_of_day < earlier_nanoseconds_of_day;
+
+    // backwards, an overflowed month end can land in the month after the target month, and otherwise an overflowed month end, a rejected month and the time of day take at most 3 months
+    for months in (months_between.saturating_sub(3).max(0)..=months_between.saturating_add(1)).rev()
+    {
+        let mut year = anchor.year();
+        let mut month = anchor.month0() as i32;
+        let mut date = anchor.day() as i32;
+
+        if month_add_with_end_of_month(
+            &mut year,
+            &mut month,
+            &mut date,
+            months * sign,
+            end_of_month,
+        )
+        .is_none()
+        {
+            continue;
+        }
+
+        let intermediate_days =
+            NaiveDate::from_ymd_opt(year, month as u32 + 1, date as u32)?.num_days_from_ce() as i64;
+
+        let mut days = (target_days - intermediate_days) * sign as i64;
+
+        if time_remains {
+            days -= 1;
+        }
+
+        if days < 0 {
+            continue;
+        }
+
+        return Some(DateDiffResult {
+            years:  months / 12,
+            months: months % 12,
+            days:   i32::try_from(days).ok()?,
+        });
+    }
+
+    None
+}
+
+#[cfg(feature = "chrono")]
+mod private {
+    use chrono::NaiveDateTime;
+
+    pub trait Sealed {
+        fn wall_clock(&self) -> NaiveDateTime;
+
+        fn offset_seconds(&self) -> i32;
+
+        // the offset of the time zone of `self` at a date-time on the wall clock
+        fn offset_seconds_at(&self, local: &NaiveDateTime) -> i32;
+
+        // the wall clock of `self` in the time zone of `other`
+        fn wall_clock_in_time_zone_of(&self, other: &Self) -> NaiveDateTime;
+    }
+}
+
+#[cfg(feature = "chrono")]
+/// The date-times which can be calculated by the diff functions with a `TimeSemantics`, e.g. `date_time_diff_with_options`, i.e. `NaiveDateTime` and `DateTime<Tz>`.
+///
+/// A `NaiveDateTime` has no offset, so it is counted in the same way with every `TimeSemantics`.
+pub trait DateTimeLike: private::Sealed + Datelike + Timelike + Ord {}
+
+#[cfg(feature = "chrono")]
+impl private::Sealed for NaiveDateTime {
+    #[inline]
+    fn wall_clock(&self) -> NaiveDateTime {
+        *self
+    }
+
+    #[inline]
+    fn offset_seconds(&self) -> i32 {
+        0
+    }
+
+    #[inline]
+    fn offset_seconds_at(&self, _local: &NaiveDateTime) -> i32 {
+        0
+    }
+
+    #[inline]
+    fn wall_clock_in_time_zone_of(&self, _other: &Self) -> NaiveDateTime {
+        *self
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl DateTimeLike for NaiveDateTime {}
+
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> private::Sealed for DateTime<Tz> {
+    #[inline]
+    fn wall_clock(&self) -> NaiveDateTime {
+        self.naive_local()
+    }
+
+    #[inline]
+    fn offset_seconds(&self) -> i32 {
+        self.offset().fix().local_minus_utc()
+    }
+
+    // the earlier one of an ambiguous date-time, and the offset before the gap for a date-time which does not exist
+    fn offset_seconds_at(&self, local: &NaiveDateTime) -> i32 {
+        match _disambiguate(&self.timezone(), local, Disambiguation::Compatible) {
+            Ok(v) => (*local - v.naive_utc()).num_seconds() as i32,
+            Err(_) => self.offset_seconds(),
+        }
+    }
+
+    #[inline]
+    fn wall_clock_in_time_zone_of(&self, other: &Self) -> NaiveDateTime {
+        self.with_timezone(&other.timezone()).naive_local()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl<Tz: TimeZone> DateTimeLike for DateTime<Tz> {}
+
+// the date-time with the time of day of `from` which the days from `from` reach before passing `to`
+#[cfg(feature = "chrono")]
+fn intermediate_date_time(
+    from: &NaiveDateTime,
+    to: &NaiveDateTime,
+    start_from_later: bool,
+) -> Option<NaiveDateTime> {
+    let v = to.date().and_time(from.time());
+
+    if start_from_later {
+        let v = if v < *to { v.checked_add_signed(Duration::days(1))? } else { v };
+
+        Some(v.min(*from))
+    } else {
+        let v = if v > *to { v.checked_sub_signed(Duration::days(1))? } else { v };
+
+        Some(v.max(*from))
+    }
+}
+
+// the wall clock of `date_time` if its offset were `offset` seconds
+#[cfg(feature = "chrono")]
+#[inline]
+fn wall_clock_at_offset(date_time: &impl DateTimeLike, offset: i32) -> Option<NaiveDateTime> {
+    date_time
+        .wall_clock()
+        .checked_add_signed(Duration::seconds(offset as i64 - date_time.offset_seconds() as i64))
+}
+
+// the date-times on a wall clock which `from` and `to` are counted on, and the intermediate date-time if the exact elapsed time after it is a day or longer, which the wall clock would count as one more day
+#[cfg(feature = "chrono")]
+fn wall_clocks<DT: DateTimeLike>(
+    from: &DT,
+    to: &DT,
+    time_semantics: TimeSemantics,
+    start_from_later: bool,
+) -> Option<(NaiveDateTime, NaiveDateTime, Option<NaiveDateTime>)> {
+    let from_wall_clock = from.wall_clock();
+
+    match time_semantics {
+        TimeSemantics::WallClock => Some((from_wall_clock, to.wall_clock(), None)),
+        TimeSemantics::Exact => {
+            Some((from_wall_clock, wall_clock_at_offset(to, from.offset_seconds())?, None))
+        },
+        TimeSemantics::Hybrid => {
+            // the time after the days is the exact elapsed time from the intermediate date-time, so `to` is moved to the offset there, and the days are counted on `to` in the time zone of `from`
+            let mut intermediate = intermediate_date_time(
+                &from_wall_clock,
+                &to.wall_clock_in_time_zone_of(from),
+                start_from_later,
+            )?;
+
+            loop {
+                let offset = if intermediate == from_wall_clock {
+                    from.offset_seconds()
+                } else {
+                    from.offset_seconds_at(&intermediate)
+                };
+
+                let to_wall_clock = wall_clock_at_offset(to, offset)?;
+
+                // the intermediate date-time passes `to` if the offset there is larger, so it goes back a day
+                let passed = if start_from_later {
+                    to_wall_clock > intermediate
+                } else {
+                    to_wall_clock < intermediate
+                };
+
+                if passed && intermediate != from_wall_clock {
+                    intermediate = if start_from_later {
+                        intermediate.checked_add_signed(Duration::days(1))?.min(from_wall_clock)
+                    } else {
+                        intermediate.checked_sub_signed(Duration::days(1))?.max(from_wall_clock)
+                    };
+
+                    continue;
+                }
+
+                let long_time = (to_wall_clock - intermediate).num_days() != 0;
+
+                return Some((from_wall_clock, to_wall_clock, long_time.then_some(intermediate)));
+            }
         },
     }
 }
 
+#[cfg(feature = "chrono")]
 /// Calculate the difference between two `DateTime` instances.
 ///
 /// # Example
@@ -425,15 +877,46 @@ fn _date_diff(
 /// ```
 #[inline]
 pub fn date_diff<DT: Datelike + Timelike + Ord>(from: DT, to: DT) -> DateDiffResult {
+    date_time_diff(from, to).into()
+}
+
+#[cfg(feature = "chrono")]
+/// Calculate the difference between two date-only instances (e.g. `NaiveDate`).
+///
+/// Unlike `date_diff`, this function does not require `Timelike`, so the time of day is not taken into account.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{date_only_diff, DateDiffResult};
+///
+/// let date = NaiveDate::from_ymd_opt(2020, 2, 27).unwrap();
+/// let date_after_1_year_2_days = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+///
+/// assert_eq!(
+///     DateDiffResult {
+///         years: 1,
+///         days: 2,
+///         ..DateDiffResult::default()
+///     },
+///     date_only_diff(date, date_after_1_year_2_days)
+/// );
+/// ```
+#[inline]
+pub fn date_only_diff<D: Datelike + Ord>(from: D, to: D) -> DateDiffResult {
     match to.cmp(&from) {
-        Ordering::Greater => _date_diff(from, to, false).result,
-        Ordering::Less => _date_diff(to, from, true).result.into_neg(),
+        Ordering::Greater => _date_diff(_ymd(&from), _ymd(&to), 0, 0, false),
+        Ordering::Less => _date_diff(_ymd(&to), _ymd(&from), 0, 0, true).into_neg(),
         Ordering::Equal => DateDiffResult::default(),
     }
 }
 
+#[cfg(feature = "chrono")]
 /// Calculate the difference between two `DateTime` instances.
 ///
+/// Every component is counted on the wall clock, as `TimeSemantics::WallClock`. Use `date_time_diff_with_options` for other semantics.
+///
 /// # Example
 ///
 /// ```rust
@@ -456,47 +939,573 @@ pub fn date_diff<DT: Datelike + Timelike + Ord>(from: DT, to: DT) -> DateDiffRes
 /// ```
 #[inline]
 pub fn date_time_diff<DT: Datelike + Timelike + Ord>(from: DT, to: DT) -> DateTimeDiffResult {
+    let from = (_ymd(&from), _date_time_nanoseconds_of_day(&from));
+    let to = (_ymd(&to), _date_time_nanoseconds_of_day(&to));
+
+    // the wall clocks are compared as they are, e.g. 02:10 CET is before 02:30 CEST on the day DST ends
     match to.cmp(&from) {
-        Ordering::Greater => {
-            let date_diff = _date_diff(from, to, false);
-
-            let time_diff = _time_diff(
-                date_diff.earlier_nanoseconds_of_day,
-                date_diff.later_nanoseconds_of_day,
-            );
-
-            let date_diff = date_diff.result;
-
-            DateTimeDiffResult {
-                years:       date_diff.years,
-                months:      date_diff.months,
-                days:        date_diff.days,
-                hours:       time_diff.hours,
-                minutes:     time_diff.minutes,
-                seconds:     time_diff.seconds,
-                nanoseconds: time_diff.nanoseconds,
-            }
+        Ordering::Greater => _date_and_nanoseconds_of_day_diff(from, to, false),
+        Ordering::Less => _date_and_nanoseconds_of_day_diff(to, from, true),
+        Ordering::Equal => DateTimeDiffResult::default(),
+    }
+}
+
+#[cfg(feature = "chrono")]
+/// Calculate the difference between two `DateTime` instances in different time zones. The month and day boundaries are those of `reference_tz`, i.e. both are converted to `reference_tz` first.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{date_diff_in_time_zone, DateDiffResult};
+///
+/// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+///
+/// // 2000-03-01T00:00:00+09:00 is still 2000-02-29 in UTC
+/// let birth = tokyo.with_ymd_and_hms(2000, 3, 1, 0, 0, 0).unwrap();
+/// let event = Utc.with_ymd_and_hms(2000, 3, 31, 20, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     DateDiffResult {
+///         months: 1,
+///         ..DateDiffResult::default()
+///     },
+///     date_diff_in_time_zone(birth, event, &tokyo)
+/// );
+///
+/// assert_eq!(
+///     DateDiffResult {
+///         months: 1,
+///         days: 2,
+///         ..DateDiffResult::default()
+///     },
+///     date_diff_in_time_zone(birth, event, &Utc)
+/// );
+/// ```
+#[inline]
+pub fn date_diff_in_time_zone<Tz1: TimeZone, Tz2: TimeZone, Tz: TimeZone>(
+    from: DateTime<Tz1>,
+    to: DateTime<Tz2>,
+    reference_tz: &Tz,
+) -> DateDiffResult {
+    date_time_diff_in_time_zone(from, to, reference_tz).into()
+}
+
+#[cfg(feature = "chrono")]
+/// Calculate the difference between two `DateTime` instances in different time zones. The month and day boundaries are those of `reference_tz`, i.e. both are converted to `reference_tz` first.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{date_time_diff_in_time_zone, DateTimeDiffResult};
+///
+/// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+///
+/// let from = tokyo.with_ymd_and_hms(2000, 1, 31, 8, 0, 0).unwrap();
+/// let to = Utc.with_ymd_and_hms(2000, 2, 29, 0, 0, 0).unwrap();
+///
+/// // 2000-01-31T08:00:00+09:00 is 2000-01-30T23:00:00Z
+/// assert_eq!(
+///     DateTimeDiffResult {
+///         days: 29,
+///         hours: 1,
+///         ..DateTimeDiffResult::default()
+///     },
+///     date_time_diff_in_time_zone(from, to, &Utc)
+/// );
+/// ```
+#[inline]
+pub fn date_time_diff_in_time_zone<Tz1: TimeZone, Tz2: TimeZone, Tz: TimeZone>(
+    from: DateTime<Tz1>,
+    to: DateTime<Tz2>,
+    reference_tz: &Tz,
+) -> DateTimeDiffResult {
+    date_time_diff(from.with_timezone(reference_tz), to.with_timezone(reference_tz))
+}
+
+#[cfg(feature = "chrono")]
+/// Calculate the difference between two `DateTime` instances with the largest and the smallest units of the result. Return `None` if a component cannot be represented by an `i32`, e.g. the number of nanoseconds in 3 seconds.
+///
+/// If the largest unit is `Unit::Weeks` or smaller, the time between `from` and `to` is divided into the units directly. The time is counted by `options.time_semantics`. `DateTimeDiffResult` has no weeks component, so weeks are expressed in days.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{
+///     date_time_diff_with_options, DateTimeDiffResult, DiffOptions, Unit,
+/// };
+///
+/// let from = Local.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+/// let to = Local.with_ymd_and_hms(2021, 3, 2, 12, 30, 0).unwrap();
+///
+/// assert_eq!(
+///     Some(DateTimeDiffResult {
+///         months: 14,
+///         days: 1,
+///         ..DateTimeDiffResult::default()
+///     }),
+///     date_time_diff_with_options(from, to, &DiffOptions {
+///         largest_unit: Unit::Months,
+///         smallest_unit: Unit::Days,
+///         ..DiffOptions::default()
+///     })
+/// );
+///
+/// assert_eq!(
+///     Some(DateTimeDiffResult {
+///         days: 426,
+///         hours: 12,
+///         ..DateTimeDiffResult::default()
+///     }),
+///     date_time_diff_with_options(from, to, &DiffOptions {
+///         largest_unit: Unit::Days,
+///         smallest_unit: Unit::Hours,
+///         ..DiffOptions::default()
+///     })
+/// );
+/// ```
+pub fn date_time_diff_with_options<DT: DateTimeLike>(
+    from: DT,
+    to: DT,
+    options: &DiffOptions,
+) -> Option<DateTimeDiffResult> {
+    let result = date_time_weeks_diff_with_options(from, to, options)?;
+
+    Some(DateTimeDiffResult {
+        years:       result.years,
+        months:      result.months,
+        days:        result.weeks * 7 + result.days,
+        hours:       result.hours,
+        minutes:     result.minutes,
+        seconds:     result.seconds,
+        nanoseconds: result.nanoseconds,
+    })
+}
+
+#[cfg(feature = "chrono")]
+/// Calculate the difference between two `DateTime` instances with weeks.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{date_time_weeks_diff, DateTimeWeeksDiffResult};
+///
+/// let date = Local.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+/// let date_after_1_month_2_weeks_1_day =
+///     Local.with_ymd_and_hms(2000, 2, 16, 0, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     DateTimeWeeksDiffResult {
+///         months: 1,
+///         weeks: 2,
+///         days: 1,
+///         ..DateTimeWeeksDiffResult::default()
+///     },
+///     date_time_weeks_diff(date, date_after_1_month_2_weeks_1_day)
+/// );
+/// ```
+#[inline]
+pub fn date_time_weeks_diff<DT: Datelike + Timelike + Ord>(
+    from: DT,
+    to: DT,
+) -> DateTimeWeeksDiffResult {
+    let result = date_time_diff(from, to);
+
+    DateTimeWeeksDiffResult {
+        years:       result.years,
+        months:      result.months,
+        weeks:       result.days / 7,
+        days:        result.days % 7,
+        hours:       result.hours,
+        minutes:     result.minutes,
+        seconds:     result.seconds,
+        nanoseconds: result.nanoseconds,
+    }
+}
+
+#[cfg(feature = "chrono")]
+/// Calculate the difference between two `DateTime` instances with weeks, and with the largest and the smallest units of the result. Return `None` if a component cannot be represented by an `i32`.
+///
+/// Weeks are split from the days if `Unit::Weeks` is between the largest and the smallest units. If the largest unit is `Unit::Weeks`, the weeks are counted from the total number of days.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{
+///     date_time_weeks_diff_with_options, DateTimeWeeksDiffResult,
+///     DiffOptions, Unit,
+/// };
+///
+/// let from = Local.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+/// let to = Local.with_ymd_and_hms(2020, 9, 1, 0, 0, 0).unwrap();
+///
+/// assert_eq!(
+///     Some(DateTimeWeeksDiffResult {
+///         weeks: 34,
+///         days: 6,
+///         ..DateTimeWeeksDiffResult::default()
+///     }),
+///     date_time_weeks_diff_with_options(from, to, &DiffOptions {
+///         largest_unit: Unit::Weeks,
+///         smallest_unit: Unit::Days,
+///         ..DiffOptions::default()
+///     })
+/// );
+/// ```
+pub fn date_time_weeks_diff_with_options<DT: DateTimeLike>(
+    from: DT,
+    to: DT,
+    options: &DiffOptions,
+) -> Option<DateTimeWeeksDiffResult> {
+    // the wall clocks are compared as they are, e.g. 02:10 CET is before 02:30 CEST on the day DST ends
+    let ordering = if options.time_semantics == TimeSemantics::WallClock {
+        to.wall_clock().cmp(&from.wall_clock())
+    } else {
+        to.cmp(&from)
+    };
+
+    let start_from_later = match ordering {
+        Ordering::Greater => false,
+        Ordering::Less => true,
+        Ordering::Equal => return Some(DateTimeWeeksDiffResult::default()),
+    };
+
+    let smallest_unit = options.smallest_unit;
+    let largest_unit = options.largest_unit.max(smallest_unit);
+
+    // without days, the hybrid semantics leaves only the exact elapsed time
+    let time_semantics = match options.time_semantics {
+        TimeSemantics::Hybrid if largest_unit < Unit::Days => TimeSemantics::Exact,
+        v => v,
+    };
+
+    let (from, to, intermediate) = wall_clocks(&from, &to, time_semantics, start_from_later)?;
+
+    // a day or longer of the exact elapsed time after the intermediate date-time, e.g. the 24 hours 40 minutes from 15:51 on the day DST ends back to 16:11 on the day before, is kept out of the days
+    let long_time = match intermediate {
+        Some(v) if largest_unit >= Unit::Days && smallest_unit <= Unit::Hours => {
+            Some((v, (to - v).num_nanoseconds()?.unsigned_abs() as i128))
         },
-        Ordering::Less => {
-            let date_diff = _date_diff(to, from, true);
-
-            let time_diff = _time_diff(
-                date_diff.earlier_nanoseconds_of_day,
-                date_diff.later_nanoseconds_of_day,
-            );
-
-            let date_diff = date_diff.result;
-
-            DateTimeDiffResult {
-                years:       -date_diff.years,
-                months:      -date_diff.months,
-                days:        -date_diff.days,
-                hours:       -time_diff.hours,
-                minutes:     -time_diff.minutes,
-                seconds:     -time_diff.seconds,
-                nanoseconds: -time_diff.nanoseconds,
-            }
+        _ => None,
+    };
+
+    let (earlier, later) = if start_from_later { (to, from) } else { (from, to) };
+
+    let rounding_increment = i32::try_from(options.rounding_increment.max(1)).ok()?;
+
+    // wall-clock date-times to do the calendar arithmetic for rounding
+    let rounding_range = if options.rounding_mode != RoundingMode::Trunc || rounding_increment > 1 {
+        if start_from_later {
+            Some((later, earlier))
+        } else {
+            Some((earlier, later))
+        }
+    } else {
+        None
+    };
+
+    // the calendar units are counted up to the intermediate date-time
+    let (earlier, later) = match long_time {
+        Some((v, _)) if start_from_later => (v, later),
+        Some((v, _)) => (earlier, v),
+        None => (earlier, later),
+    };
+
+    let earlier_nanoseconds_of_day = _date_time_nanoseconds_of_day(&earlier);
+    let later_nanoseconds_of_day = _date_time_nanoseconds_of_day(&later);
+
+    let mut result = if largest_unit >= Unit::Months {
+        let date_diff = if options.end_of_month == EndOfMonth::Clamp {
+            _date_diff(
+                _ymd(&earlier),
+                _ymd(&later),
+                earlier_nanoseconds_of_day,
+                later_nanoseconds_of_day,
+                start_from_later,
+            )
+        } else {
+            _date_diff_with_end_of_month(
+                &earlier,
+                &later,
+                earlier_nanoseconds_of_day,
+                later_nanoseconds_of_day,
+                start_from_later,
+                options.end_of_month,
+            )?
+        };
+
+        let time_diff = _time_diff(earlier_nanoseconds_of_day, later_nanoseconds_of_day);
+
+        let mut result = DateTimeWeeksDiffResult {
+            years:       date_diff.years,
+            months:      date_diff.months,
+            weeks:       0,
+            days:        date_diff.days,
+            hours:       time_diff.hours,
+            minutes:     time_diff.minutes,
+            seconds:     time_diff.seconds,
+            nanoseconds: time_diff.nanoseconds,
+        };
+
+        if largest_unit == Unit::Months {
+            result.months = result.years.checked_mul(12)?.checked_add(result.months)?;
+            result.years = 0;
+        }
+
+        if smallest_unit <= Unit::Weeks {
+            result.weeks = result.days / 7;
+            result.days %= 7;
+        }
+
+        result
+    } else {
+        let days = later.num_days_from_ce() as i128 - earlier.num_days_from_ce() as i128;
+
+        _nanoseconds_to_units_up_to(
+            days * DAY_NANOSECONDS as i128 + later_nanoseconds_of_day as i128
+                - earlier_nanoseconds_of_day as i128,
+            largest_unit,
+        )?
+    };
+
+    if let Some((_, nanoseconds)) = long_time {
+        let time = _nanoseconds_to_units_up_to(nanoseconds, Unit::Hours)?;
+
+        result.hours = time.hours;
+        result.minutes = time.minutes;
+        result.seconds = time.seconds;
+        result.nanoseconds = time.nanoseconds;
+    }
+
+    if smallest_unit > Unit::Nanoseconds {
+        result.nanoseconds = 0;
+    }
+
+    if smallest_unit > Unit::Seconds {
+        result.seconds = 0;
+    }
+
+    if smallest_unit > Unit::Minutes {
+        result.minutes = 0;
+    }
+
+    if smallest_unit > Unit::Hours {
+        result.hours = 0;
+    }
+
+    if smallest_unit > Unit::Days {
+        result.days = 0;
+    }
+
+    if smallest_unit > Unit::Weeks {
+        result.weeks = 0;
+    }
+
+    if smallest_unit > Unit::Months {
+        result.months = 0;
+    }
+
+    if start_from_later {
+        result = result.into_neg();
+    }
+
+    match rounding_range {
+        Some((from, to)) => _round(from, to, result, options, rounding_increment),
+        None => Some(result),
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[inline]
+pub(crate) fn _to_naive_date_time(date_time: &(impl Datelike + Timelike)) -> Option<NaiveDateTime> {
+    NaiveDate::from_ymd_opt(date_time.year(), date_time.month(), date_time.day())?.and_hms_nano_opt(
+        date_time.hour(),
+        date_time.minute(),
+        date_time.second(),
+        date_time.nanosecond(),
+    )
+}
+
+#[cfg(feature = "chrono")]
+#[inline]
+pub(crate) fn _wall_clock_nanoseconds(date_time: &NaiveDateTime) -> i128 {
+    date_time.num_days_from_ce() as i128 * DAY_NANOSECONDS as i128
+        + _date_time_nanoseconds_of_day(date_time) as i128
+}
+
+#[cfg(feature = "chrono")]
+#[inline]
+pub(crate) fn _component_mut(
+    result: &mut DateTimeWeeksDiffResult,
+    smallest_unit: Unit,
+) -> &mut i32 {
+    match smallest_unit {
+        Unit::Nanoseconds => &mut result.nanoseconds,
+        Unit::Seconds => &mut result.seconds,
+        Unit::Minutes => &mut result.minutes,
+        Unit::Hours => &mut result.hours,
+        Unit::Days => &mut result.days,
+        Unit::Weeks => &mut result.weeks,
+        Unit::Months => &mut result.months,
+        Unit::Years => &mut result.years,
+    }
+}
+
+// find `lower` and `upper` which differ by one increment of `unit` such that `to` is in [`from` + `lower`, `from` + `upper`)
+#[cfg(feature = "chrono")]
+pub(crate) fn _bracket(
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+    mut lower: DateTimeWeeksDiffResult,
+    unit: Unit,
+    increment: i32,
+    add_options: &AddOptions,
+) -> Option<(DateTimeWeeksDiffResult, NaiveDateTime, DateTimeWeeksDiffResult, NaiveDateTime)> {
+    let step = if to < from { -increment } else { increment };
+    let is_before = |a: NaiveDateTime, b: NaiveDateTime| if step > 0 { a < b } else { a > b };
+
+    let anchor = Utc.from_utc_datetime(&from);
+
+    let mut lower_date_time =
+        add_date_time_diff_with_options(anchor, &lower, add_options).single()?.naive_utc();
+
+    while is_before(to, lower_date_time) {
+        let component = _component_mut(&mut lower, unit);
+        *component = component.checked_sub(step)?;
+
+        lower_date_time =
+            add_date_time_diff_with_options(anchor, &lower, add_options).single()?.naive_utc();
+    }
+
+    let mut upper = lower.clone();
+
+    let component = _component_mut(&mut upper, unit);
+    *component = component.checked_add(step)?;
+
+    let mut upper_date_time =
+        add_date_time_diff_with_options(anchor, &upper, add_options).single()?.naive_utc();
+
+    // the truncated difference can be more than one increment away from `to` because of the clamping, e.g. 1952-10-31 to 1954-11-30 12:00 is 2 years 30 days 12 hours, but 1952-10-31 + 2 years 1 month is 1954-11-30
+    while !is_before(to, upper_date_time) {
+        lower = upper.clone();
+        lower_date_time = upper_date_time;
+
+        let component = _component_mut(&mut upper, unit);
+        *component = component.checked_add(step)?;
+
+        upper_date_time =
+            add_date_time_diff_with_options(anchor, &upper, add_options).single()?.naive_utc();
+    }
+
+    Some((lower, lower_date_time, upper, upper_date_time))
+}
+
+// round the truncated `lower` by the increment of the smallest unit
+#[cfg(feature = "chrono")]
+fn _round(
+    from: NaiveDateTime,
+    to: NaiveDateTime,
+    mut lower: DateTimeWeeksDiffResult,
+    options: &DiffOptions,
+    rounding_increment: i32,
+) -> Option<DateTimeWeeksDiffResult> {
+    let negative = to < from;
+
+    let component = _component_mut(&mut lower, options.smallest_unit);
+    *component -= *component % rounding_increment;
+
+    if options.rounding_mode == RoundingMode::Trunc {
+        return Some(lower);
+    }
+
+    let add_options = AddOptions {
+        end_of_month: options.end_of_month,
+        ..AddOptions::default()
+    };
+
+    let (mut lower, lower_date_time, mut upper, upper_date_time) =
+        _bracket(from, to, lower, options.smallest_unit, rounding_increment, &add_options)?;
+
+    let anchor = Utc.from_utc_datetime(&from);
+
+    // the span of one increment is measured from the actual date-time, so it depends on the calendar
+    let progress = (_wall_clock_nanoseconds(&to) - _wall_clock_nanoseconds(&lower_date_time)).abs();
+
+    if progress == 0 {
+        return Some(lower);
+    }
+
+    let span = (_wall_clock_nanoseconds(&upper_date_time)
+        - _wall_clock_nanoseconds(&lower_date_time))
+    .abs();
+
+    let round_up = match options.rounding_mode {
+        RoundingMode::Trunc => unreachable!(),
+        RoundingMode::Floor => negative,
+        RoundingMode::Ceil => !negative,
+        RoundingMode::HalfExpand => progress * 2 >= span,
+        RoundingMode::HalfEven => match (progress * 2).cmp(&span) {
+            Ordering::Less => false,
+            Ordering::Greater => true,
+            Ordering::Equal => {
+                (*_component_mut(&mut lower, options.smallest_unit) / rounding_increment) % 2 != 0
+            },
         },
-        Ordering::Equal => DateTimeDiffResult::default(),
+    };
+
+    if !round_up {
+        return Some(lower);
     }
+
+    // carry the rounded component into the larger units if they are reached from the actual date-time, e.g. 2020-01-31 + 12 months is 2021-01-31, which is 1 year
+    const UNITS: [Unit; 8] = [
+        Unit::Nanoseconds,
+        Unit::Seconds,
+        Unit::Minutes,
+        Unit::Hours,
+        Unit::Days,
+        Unit::Weeks,
+        Unit::Months,
+        Unit::Years,
+    ];
+
+    let largest_unit = options.largest_unit.max(options.smallest_unit);
+
+    for unit in
+        UNITS.into_iter().filter(|unit| *unit > options.smallest_unit && *unit <= largest_unit)
+    {
+        let mut candidate = upper.clone();
+
+        for smaller_unit in UNITS.into_iter().filter(|smaller_unit| *smaller_unit < unit) {
+            *_component_mut(&mut candidate, smaller_unit) = 0;
+        }
+
+        let component = _component_mut(&mut candidate, unit);
+        *component = component.checked_add(if negative { -1 } else { 1 })?;
+
+        let candidate_date_time =
+            match add_date_time_diff_with_options(anchor, &candidate, &add_options).single() {
+                Some(v) => v.naive_utc(),
+                None => break,
+            };
+
+        // the carried result must still be rounded up, i.e. between `to` and the rounded date-time
+        let in_range = if negative {
+            candidate_date_time >= upper_date_time && candidate_date_time <= to
+        } else {
+            candidate_date_time <= upper_date_time && candidate_date_time >= to
+        };
+
+        if !in_range {
+            break;
+        }
+
+        upper = candidate;
+    }
+
+    Some(upper)
 }
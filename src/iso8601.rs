@@ -0,0 +1,331 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+use super::{constants::*, DateDiffResult, DateTimeDiffResult};
+
+/// Errors that can occur when parsing an ISO 8601 duration.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ParseDiffError {
+    /// The input is not a well-formed ISO 8601 duration, e.g. `P1H`, `PT`, `P1D2Y`.
+    InvalidFormat,
+    /// A component cannot be represented by an `i32`.
+    OutOfRange,
+    /// The input has a time part but the target type (`DateDiffResult`) cannot store it.
+    UnexpectedTime,
+}
+
+impl Display for ParseDiffError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseDiffError::InvalidFormat => f.write_str("invalid ISO 8601 duration"),
+            ParseDiffError::OutOfRange => f.write_str("duration component out of range"),
+            ParseDiffError::UnexpectedTime => {
+                f.write_str("time components are not allowed in a date duration")
+            },
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn fmt_iso8601(
+    f: &mut Formatter<'_>,
+    years: i32,
+    months: i32,
+    days: i32,
+    hours: i32,
+    minutes: i32,
+    seconds: i32,
+    nanoseconds: i32,
+    zero: &str,
+) -> fmt::Result {
+    // seconds and nanoseconds are merged so that they can be written as a decimal fraction, which is exact if `nanoseconds` is less than a second and has the sign of `seconds`; otherwise the total is written, e.g. 1 second and -5 nanoseconds are `0.999999995S`
+    let seconds = seconds as i64 * SECOND_NANOSECONDS as i64 + nanoseconds as i64;
+
+    let components = [years as i64, months as i64, days as i64, hours as i64, minutes as i64];
+
+    if components.iter().all(|v| *v == 0) && seconds == 0 {
+        return f.write_str(zero);
+    }
+
+    // use a leading sign only if no component is positive, otherwise every negative component carries its own sign
+    let sign = if components.iter().all(|v| *v <= 0) && seconds <= 0 {
+        f.write_str("-")?;
+
+        -1
+    } else {
+        1
+    };
+
+    f.write_str("P")?;
+
+    for (value, designator) in components[..3].iter().zip(["Y", "M", "D"]) {
+        if *value != 0 {
+            write!(f, "{}{designator}", value * sign)?;
+        }
+    }
+
+    if hours == 0 && minutes == 0 && seconds == 0 {
+        return Ok(());
+    }
+
+    f.write_str("T")?;
+
+    for (value, designator) in components[3..].iter().zip(["H", "M"]) {
+        if *value != 0 {
+            write!(f, "{}{designator}", value * sign)?;
+        }
+    }
+
+    if seconds != 0 {
+        let seconds = seconds * sign;
+
+        if seconds < 0 {
+            f.write_str("-")?;
+        }
+
+        let seconds = seconds.unsigned_abs();
+
+        write!(f, "{}", seconds / SECOND_NANOSECONDS)?;
+
+        let mut fraction = seconds % SECOND_NANOSECONDS;
+
+        if fraction != 0 {
+            let mut width = 9;
+
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                width -= 1;
+            }
+
+            write!(f, ".{fraction:0width$}")?;
+        }
+
+        f.write_str("S")?;
+    }
+
+    Ok(())
+}
+
+fn parse_iso8601(s: &str, allow_time: bool) -> Result<DateTimeDiffResult, ParseDiffError> {
+    let bytes = s.as_bytes();
+    let length = bytes.len();
+
+    let mut i = 0;
+
+    let negative = match bytes.first() {
+        Some(b'-') => {
+            i += 1;
+
+            true
+        },
+        Some(b'+') => {
+            i += 1;
+
+            false
+        },
+        _ => false,
+    };
+
+    if bytes.get(i) != Some(&b'P') {
+        return Err(ParseDiffError::InvalidFormat);
+    }
+
+    i += 1;
+
+    let mut result = DateTimeDiffResult::default();
+
+    let mut in_time = false;
+    // 1 ~ 7 for Y, M, W, D, H, M, S; used to check the order of designators
+    let mut last_rank = 0;
+
+    while i < length {
+        if bytes[i] == b'T' {
+            if in_time {
+                return Err(ParseDiffError::InvalidFormat);
+            }
+
+            if !allow_time {
+                return Err(ParseDiffError::UnexpectedTime);
+            }
+
+            in_time = true;
+            i += 1;
+
+            if i == length {
+                // e.g. P1DT
+                return Err(ParseDiffError::InvalidFormat);
+            }
+
+            continue;
+        }
+
+        // PostgreSQL allows every component to carry its own sign, e.g. P1Y-2M
+        let component_negative = match bytes[i] {
+            b'-' => {
+                i += 1;
+
+                true
+            },
+            b'+' => {
+                i += 1;
+
+                false
+            },
+            _ => false,
+        };
+
+        let start = i;
+        let mut integer: i64 = 0;
+
+        while i < length && bytes[i].is_ascii_digit() {
+            integer = integer
+                .checked_mul(10)
+                .and_then(|v| v.checked_add((bytes[i] - b'0') as i64))
+                .ok_or(ParseDiffError::OutOfRange)?;
+
+            i += 1;
+        }
+
+        if i == start {
+            return Err(ParseDiffError::InvalidFormat);
+        }
+
+        let mut fraction: Option<i64> = None;
+
+        if i < length && (bytes[i] == b'.' || bytes[i] == b',') {
+            i += 1;
+
+            let start = i;
+            let mut value: i64 = 0;
+
+            while i < length && bytes[i].is_ascii_digit() {
+                value = value * 10 + (bytes[i] - b'0') as i64;
+
+                i += 1;
+
+                if i - start > 9 {
+                    // more precise than nanoseconds
+                    return Err(ParseDiffError::InvalidFormat);
+                }
+            }
+
+            if i == start {
+                return Err(ParseDiffError::InvalidFormat);
+            }
+
+            for _ in (i - start)..9 {
+                value *= 10;
+            }
+
+            fraction = Some(value);
+        }
+
+        let rank = match (in_time, bytes.get(i)) {
+            (false, Some(b'Y')) => 1,
+            (false, Some(b'M')) => 2,
+            (false, Some(b'W')) => 3,
+            (false, Some(b'D')) => 4,
+            (true, Some(b'H')) => 5,
+            (true, Some(b'M')) => 6,
+            (true, Some(b'S')) => 7,
+            _ => return Err(ParseDiffError::InvalidFormat),
+        };
+
+        i += 1;
+
+        if rank <= last_rank || (fraction.is_some() && rank != 7) {
+            return Err(ParseDiffError::InvalidFormat);
+        }
+
+        last_rank = rank;
+
+        let sign = if negative != component_negative { -1 } else { 1 };
+
+        if rank == 7 {
+            // the total seconds of `i32::MAX` seconds and 1.5 billion nanoseconds are past `i32`, so the excess whole seconds go back into `nanoseconds`
+            let seconds = (integer * sign).clamp(i32::MIN as i64, i32::MAX as i64);
+            let nanoseconds = (integer * sign - seconds)
+                .checked_mul(SECOND_NANOSECONDS as i64)
+                .and_then(|v| v.checked_add(fraction.unwrap_or(0) * sign))
+                .and_then(|v| i32::try_from(v).ok())
+                .ok_or(ParseDiffError::OutOfRange)?;
+
+            result.seconds = seconds as i32;
+            result.nanoseconds = nanoseconds;
+
+            continue;
+        }
+
+        let value = i32::try_from(integer * sign).map_err(|_| ParseDiffError::OutOfRange)?;
+
+        match rank {
+            1 => result.years = value,
+            2 => result.months = value,
+            3 => result.days = value.checked_mul(7).ok_or(ParseDiffError::OutOfRange)?,
+            4 => result.days = result.days.checked_add(value).ok_or(ParseDiffError::OutOfRange)?,
+            5 => result.hours = value,
+            6 => result.minutes = value,
+            _ => unreachable!(),
+        }
+    }
+
+    if last_rank == 0 {
+        // e.g. P
+        return Err(ParseDiffError::InvalidFormat);
+    }
+
+    Ok(result)
+}
+
+impl Display for DateDiffResult {
+    /// Format this difference as an ISO 8601 duration, e.g. `P1Y2M3D`.
+    ///
+    /// If no component is positive, the whole duration is negated with a leading `-`, e.g. `-P1Y2M3D`. Otherwise, negative components are written with their own signs, e.g. `P1Y-2M`, as PostgreSQL does.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_iso8601(f, self.years, self.months, self.days, 0, 0, 0, 0, "P0D")
+    }
+}
+
+impl Display for DateTimeDiffResult {
+    /// Format this difference as an ISO 8601 duration, e.g. `P1Y2M3DT4H5M6.000000007S`.
+    ///
+    /// The `seconds` and `nanoseconds` fields are merged into one decimal number of seconds. It is parsed back as it is if `nanoseconds` is less than a second and has the sign of `seconds`. Otherwise, the whole seconds in `nanoseconds` are carried into `seconds`, e.g. 1 second and -5 nanoseconds are written as `PT0.999999995S`, which is parsed as 999999995 nanoseconds. If no component is positive, the whole duration is negated with a leading `-`, e.g. `-PT1.5S`. Otherwise, negative components are written with their own signs, e.g. `P1DT-2H`, as PostgreSQL does.
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_iso8601(
+            f,
+            self.years,
+            self.months,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.nanoseconds,
+            "PT0S",
+        )
+    }
+}
+
+impl FromStr for DateDiffResult {
+    type Err = ParseDiffError;
+
+    /// Parse an ISO 8601 duration without the time part, e.g. `P1Y2M3D`. Weeks (`W`) are converted into days.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_iso8601(s, false).map(DateDiffResult::from)
+    }
+}
+
+impl FromStr for DateTimeDiffResult {
+    type Err = ParseDiffError;
+
+    /// Parse an ISO 8601 duration, e.g. `P1Y2M3DT4H5M6.000000007S`. Weeks (`W`) are converted into days. Only the seconds can have a fraction, which is stored in `nanoseconds`. Whole seconds past the range of `i32` are also stored in `nanoseconds` if they fit, so that everything written by `Display` can be parsed.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_iso8601(s, true)
+    }
+}
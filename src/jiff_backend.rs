@@ -0,0 +1,442 @@
+/*!
+The `date_diff`, `date_time_diff` and `add_date_time_diff` functions for `jiff::civil::Date`, `jiff::civil::DateTime` and `jiff::Zoned`, and the conversions between `DateTimeDiffResult` and `jiff::Span`.
+
+```rust
+use date_differencer::{jiff_backend::*, DateTimeDiffResult};
+use jiff::{civil::date, Span};
+
+let a = date(2022, 4, 6).at(0, 0, 0, 0);
+let b = date(2023, 6, 9).at(1, 0, 0, 0);
+
+let diff = date_time_diff(a, b);
+
+assert_eq!(
+    DateTimeDiffResult {
+        years: 1,
+        months: 2,
+        days: 3,
+        hours: 1,
+        ..DateTimeDiffResult::default()
+    },
+    diff
+);
+
+assert_eq!(Some(b), add_date_time_diff(a, &diff));
+
+let span = Span::try_from(diff).unwrap();
+
+assert_eq!(b, a + span);
+```
+*/
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+};
+
+use jiff::{
+    civil::{Date, DateTime},
+    tz::AmbiguousOffset,
+    SignedDuration, Span, Zoned,
+};
+
+use super::{
+    add_diff::{_add_wall_clock, WallClock},
+    constants::*,
+    diff::_wall_clock_diff,
+    DateDiffResult, DateTimeDiff, DateTimeDiffResult, EndOfMonth,
+};
+
+mod private {
+    use jiff::civil::DateTime;
+
+    use crate::{DateTimeDiff, DateTimeDiffResult};
+
+    pub trait Sealed: Sized + Clone + Ord {
+        fn to_date_time(&self) -> DateTime;
+
+        fn with_date_time(&self, date_time: DateTime) -> Option<Self>;
+
+        // the difference from `self` to `to`, counted on the wall clock
+        #[inline]
+        fn diff(&self, to: &Self) -> DateTimeDiffResult {
+            super::wall_clock_diff(self, to)
+        }
+
+        // `self` + `date_time_diff`, added on the wall clock
+        #[inline]
+        fn add(&self, date_time_diff: &dyn DateTimeDiff) -> Option<Self> {
+            super::add_on_wall_clock(self, date_time_diff)
+        }
+    }
+}
+
+/// The types of the `jiff` crate which can be used with the functions in this module, i.e. `civil::Date`, `civil::DateTime` and `Zoned`.
+///
+/// A `Date` is at midnight. A `Zoned` is calculated as `TimeSemantics::Hybrid`, i.e. the years, the months and the days are counted on the wall clock of the time zone of `from`, and the rest is the exact elapsed time. A `Zoned` keeps its time zone when a difference is added to it.
+pub trait Backend: private::Sealed + Clone + Ord {}
+
+impl private::Sealed for Date {
+    #[inline]
+    fn to_date_time(&self) -> DateTime {
+        self.to_datetime(jiff::civil::Time::midnight())
+    }
+
+    #[inline]
+    fn with_date_time(&self, date_time: DateTime) -> Option<Self> {
+        Some(date_time.date())
+    }
+}
+
+impl Backend for Date {}
+
+impl private::Sealed for DateTime {
+    #[inline]
+    fn to_date_time(&self) -> DateTime {
+        *self
+    }
+
+    #[inline]
+    fn with_date_time(&self, date_time: DateTime) -> Option<Self> {
+        Some(date_time)
+    }
+}
+
+impl Backend for DateTime {}
+
+impl private::Sealed for Zoned {
+    #[inline]
+    fn to_date_time(&self) -> DateTime {
+        self.datetime()
+    }
+
+    #[inline]
+    fn with_date_time(&self, date_time: DateTime) -> Option<Self> {
+        date_time.to_zoned(self.time_zone().clone()).ok()
+    }
+
+    #[inline]
+    fn diff(&self, to: &Self) -> DateTimeDiffResult {
+        hybrid_diff(self, to)
+    }
+
+    fn add(&self, date_time_diff: &dyn DateTimeDiff) -> Option<Self> {
+        let date_diff = DateDiffResult {
+            years:  date_time_diff.years(),
+            months: date_time_diff.months(),
+            days:   date_time_diff.weeks().checked_mul(7)?.checked_add(date_time_diff.days())?,
+        };
+
+        // `self` is not resolved again if it does not move on the wall clock, e.g. in a fold
+        let intermediate = if date_diff == DateDiffResult::default() {
+            self.clone()
+        } else {
+            add_on_wall_clock(self, &date_diff)?
+        };
+
+        let seconds = date_time_diff.hours() as i64 * 3600
+            + date_time_diff.minutes() as i64 * 60
+            + date_time_diff.seconds() as i64;
+
+        intermediate.checked_add(SignedDuration::new(seconds, date_time_diff.nanoseconds())).ok()
+    }
+}
+
+impl Backend for Zoned {}
+
+#[inline]
+fn to_wall_clock(date_time: &impl private::Sealed) -> WallClock {
+    let v = date_time.to_date_time();
+
+    WallClock {
+        year:       v.year() as i32,
+        month:      v.month() as i32 - 1,
+        date:       v.day() as i32,
+        hour:       v.hour() as i32,
+        minute:     v.minute() as i32,
+        second:     v.second() as i32,
+        nanosecond: v.subsec_nanosecond(),
+    }
+}
+
+#[inline]
+fn from_wall_clock<T: private::Sealed>(date_time: &T, wall_clock: &WallClock) -> Option<T> {
+    let v = DateTime::new(
+        i16::try_from(wall_clock.year).ok()?,
+        (wall_clock.month + 1) as i8,
+        wall_clock.date as i8,
+        wall_clock.hour as i8,
+        wall_clock.minute as i8,
+        wall_clock.second as i8,
+        wall_clock.nanosecond,
+    )
+    .ok()?;
+
+    date_time.with_date_time(v)
+}
+
+/// Calculate the difference between two `civil::Date`, `civil::DateTime` or `Zoned` instances.
+///
+/// # Example
+///
+/// ```rust
+/// use date_differencer::{jiff_backend::date_diff, DateDiffResult};
+/// use jiff::civil::date;
+///
+/// assert_eq!(
+///     DateDiffResult {
+///         years: 1,
+///         days: 2,
+///         ..DateDiffResult::default()
+///     },
+///     date_diff(date(2020, 2, 27), date(2021, 3, 1))
+/// );
+/// ```
+#[inline]
+pub fn date_diff<T: Backend>(from: T, to: T) -> DateDiffResult {
+    date_time_diff(from, to).into()
+}
+
+/// Calculate the difference between two `civil::Date`, `civil::DateTime` or `Zoned` instances.
+///
+/// If two `Zoned` instances are in different time zones, `to` is converted to the time zone of `from` first, so `add_date_time_diff(from, &date_time_diff(from, to))` is the same instant as `to`.
+///
+/// # Example
+///
+/// ```rust
+/// use date_differencer::{jiff_backend::date_time_diff, DateTimeDiffResult};
+/// use jiff::{
+///     civil::date,
+///     tz::{self, TimeZone},
+/// };
+///
+/// let tz = TimeZone::fixed(tz::offset(9));
+///
+/// let from = date(2000, 1, 1).at(0, 0, 0, 0).to_zoned(tz.clone()).unwrap();
+/// let from_after_1_year_1_day_5_minutes =
+///     date(2001, 1, 2).at(0, 5, 0, 0).to_zoned(tz).unwrap();
+///
+/// assert_eq!(
+///     DateTimeDiffResult {
+///         years: 1,
+///         days: 1,
+///         minutes: 5,
+///         ..DateTimeDiffResult::default()
+///     },
+///     date_time_diff(from, from_after_1_year_1_day_5_minutes)
+/// );
+/// ```
+pub fn date_time_diff<T: Backend>(from: T, to: T) -> DateTimeDiffResult {
+    from.diff(&to)
+}
+
+/// Calculate `from` + `date_time_diff`. Return `None` if the result is out of the range of the `jiff` crate.
+///
+/// If the day of month does not exist after adding the years and the months, it is clamped to the last day of the month. The time components of `date_time_diff` are also added to a `civil::Date`, and the time of day of the result is dropped. For a `Zoned`, the years, the months and the days are added on the wall clock and resolved like `civil::DateTime::to_zoned`, i.e. with the compatible disambiguation, and the time is added as the exact elapsed time.
+///
+/// # Example
+///
+/// ```rust
+/// use date_differencer::{jiff_backend::add_date_time_diff, DateDiffResult};
+/// use jiff::civil::date;
+///
+/// assert_eq!(
+///     Some(date(2021, 2, 28)),
+///     add_date_time_diff(date(2021, 1, 31), &DateDiffResult {
+///         months: 1,
+///         ..DateDiffResult::default()
+///     })
+/// );
+/// ```
+#[inline]
+pub fn add_date_time_diff<T: Backend>(from: T, date_time_diff: &dyn DateTimeDiff) -> Option<T> {
+    from.add(date_time_diff)
+}
+
+fn wall_clock_diff<T: private::Sealed>(from: &T, to: &T) -> DateTimeDiffResult {
+    let (earlier, later, start_from_later) = match to.cmp(from) {
+        Ordering::Greater => (from, to, false),
+        Ordering::Less => (to, from, true),
+        Ordering::Equal => return DateTimeDiffResult::default(),
+    };
+
+    _wall_clock_diff(&to_wall_clock(earlier), &to_wall_clock(later), start_from_later)
+}
+
+// the days are counted on the wall clock up to the intermediate date-time with the time of day of `from`, resolved as `Disambiguation::Compatible` does, and the rest is the exact elapsed time from there, as `TimeSemantics::Hybrid` does
+fn hybrid_diff(from: &Zoned, to: &Zoned) -> DateTimeDiffResult {
+    let (start_from_later, day) = match to.cmp(from) {
+        Ordering::Greater => (false, SignedDuration::from_hours(-24)),
+        Ordering::Less => (true, SignedDuration::from_hours(24)),
+        Ordering::Equal => return DateTimeDiffResult::default(),
+    };
+
+    let time_zone = from.time_zone();
+    let to = to.with_time_zone(time_zone.clone());
+
+    let from_date_time = from.datetime();
+    let to_date_time = to.datetime();
+
+    // the intermediate date-time never goes beyond `from`
+    let clamp = |v: DateTime| {
+        if start_from_later {
+            v.min(from_date_time)
+        } else {
+            v.max(from_date_time)
+        }
+    };
+
+    // a day back toward `from`
+    let step = |v: DateTime| clamp(v.checked_add(day).unwrap_or(from_date_time));
+
+    let intermediate = to_date_time.date().to_datetime(from_date_time.time());
+
+    let mut intermediate = if (start_from_later && intermediate < to_date_time)
+        || (!start_from_later && intermediate > to_date_time)
+    {
+        step(intermediate)
+    } else {
+        clamp(intermediate)
+    };
+
+    loop {
+        let offset = if intermediate == from_date_time {
+            from.offset()
+        } else {
+            match time_zone.to_ambiguous_timestamp(intermediate).offset() {
+                AmbiguousOffset::Unambiguous {
+                    offset,
+                } => offset,
+                AmbiguousOffset::Gap {
+                    before, ..
+                }
+                | AmbiguousOffset::Fold {
+                    before, ..
+                } => before,
+            }
+        };
+
+        let elapsed = to_date_time.duration_since(intermediate)
+            + SignedDuration::from_secs(offset.seconds() as i64 - to.offset().seconds() as i64);
+
+        // the intermediate date-time passes `to` if the offset there is larger, so it goes back a day
+        let passed = if start_from_later { elapsed.is_positive() } else { elapsed.is_negative() };
+
+        if passed && intermediate != from_date_time {
+            intermediate = step(intermediate);
+
+            continue;
+        }
+
+        // the wall clock would count a day or longer of the exact elapsed time as one more day
+        let nanoseconds = elapsed.as_nanos();
+
+        if nanoseconds.unsigned_abs() < DAY_NANOSECONDS as u128 {
+            if let Ok(to_date_time) = intermediate.checked_add(elapsed) {
+                return wall_clock_diff(&from_date_time, &to_date_time);
+            }
+        }
+
+        let mut result = wall_clock_diff(&from_date_time, &intermediate);
+
+        result.hours = (nanoseconds / HOUR_NANOSECONDS as i128) as i32;
+        result.minutes =
+            (nanoseconds % HOUR_NANOSECONDS as i128 / MINUTE_NANOSECONDS as i128) as i32;
+        result.seconds =
+            (nanoseconds % MINUTE_NANOSECONDS as i128 / SECOND_NANOSECONDS as i128) as i32;
+        result.nanoseconds = (nanoseconds % SECOND_NANOSECONDS as i128) as i32;
+
+        return result;
+    }
+}
+
+fn add_on_wall_clock<T: private::Sealed>(from: &T, date_time_diff: &dyn DateTimeDiff) -> Option<T> {
+    let wall_clock = _add_wall_clock(to_wall_clock(from), date_time_diff, EndOfMonth::Clamp)?;
+
+    from_wall_clock(from, &wall_clock)
+}
+
+/// Errors that can occur when converting between `DateTimeDiffResult` and `jiff::Span`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SpanConversionError {
+    /// The components of the `DateTimeDiffResult` have different signs, which a `Span` cannot represent.
+    MixedSigns,
+    /// A component is out of the range of the target type.
+    OutOfRange,
+}
+
+impl Display for SpanConversionError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SpanConversionError::MixedSigns => {
+                f.write_str("the components of a span must have the same sign")
+            },
+            SpanConversionError::OutOfRange => f.write_str("span component out of range"),
+        }
+    }
+}
+
+impl TryFrom<DateTimeDiffResult> for Span {
+    type Error = SpanConversionError;
+
+    fn try_from(value: DateTimeDiffResult) -> Result<Self, Self::Error> {
+        let components = [
+            value.years,
+            value.months,
+            value.days,
+            value.hours,
+            value.minutes,
+            value.seconds,
+            value.nanoseconds,
+        ];
+
+        let negative = if components.iter().all(|v| *v >= 0) {
+            false
+        } else if components.iter().all(|v| *v <= 0) {
+            true
+        } else {
+            return Err(SpanConversionError::MixedSigns);
+        };
+
+        let abs = |v: i32| (v as i64).abs();
+
+        let span = Span::new()
+            .try_years(abs(value.years))
+            .and_then(|v| v.try_months(abs(value.months)))
+            .and_then(|v| v.try_days(abs(value.days)))
+            .and_then(|v| v.try_hours(abs(value.hours)))
+            .and_then(|v| v.try_minutes(abs(value.minutes)))
+            .and_then(|v| v.try_seconds(abs(value.seconds)))
+            .and_then(|v| v.try_nanoseconds(abs(value.nanoseconds)))
+            .map_err(|_| SpanConversionError::OutOfRange)?;
+
+        Ok(if negative { span.negate() } else { span })
+    }
+}
+
+/// The weeks are expressed in days, and the milliseconds and the microseconds are expressed in seconds plus nanoseconds.
+impl TryFrom<Span> for DateTimeDiffResult {
+    type Error = SpanConversionError;
+
+    fn try_from(value: Span) -> Result<Self, Self::Error> {
+        let to_i32 = |v: i128| i32::try_from(v).map_err(|_| SpanConversionError::OutOfRange);
+
+        // every component has the sign of the span, so the remainder has it too
+        let nanoseconds = value.get_milliseconds() as i128 * 1000000
+            + value.get_microseconds() as i128 * 1000
+            + value.get_nanoseconds() as i128;
+
+        Ok(DateTimeDiffResult {
+            years:       value.get_years() as i32,
+            months:      value.get_months(),
+            days:        to_i32(value.get_weeks() as i128 * 7 + value.get_days() as i128)?,
+            hours:       value.get_hours(),
+            minutes:     to_i32(value.get_minutes() as i128)?,
+            seconds:     to_i32(
+                value.get_seconds() as i128 + nanoseconds / SECOND_NANOSECONDS as i128,
+            )?,
+            nanoseconds: (nanoseconds % SECOND_NANOSECONDS as i128) as i32,
+        })
+    }
+}
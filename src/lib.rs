@@ -6,6 +6,8 @@ Calculate the time interval between two `DateTime` instances and output the resu
 ## Usage
 
 ```rust
+# #[cfg(feature = "chrono")]
+# {
 use chrono::prelude::*;
 
 use date_differencer::{date_diff, date_time_diff, add_date_time_diff};
@@ -36,11 +38,14 @@ println!("{:?}", date_time_diff(a, b));
 */
 
 println!("{}", add_date_time_diff(a, &date_time_diff(a, b)).unwrap()); // the same as b
+# }
 ```
 
 This library can handle leap years and odd/even number of days in a month correctly. The result of following code is a bit confusing but reasonable.
 
 ```rust
+# #[cfg(feature = "chrono")]
+# {
 use chrono::prelude::*;
 
 use date_differencer::date_diff;
@@ -73,15 +78,114 @@ Explanation:
     1. 2021-03-01 - 1 year -> 2020-03-01
     2. 2020-03-01 - 3 days -> 2020-02-27 (2020-02 has 29 days)
 */
+# }
 ```
+
+## DST Transitions
+
+`date_diff`, `date_time_diff` and `add_date_time_diff` count every component on the wall clock. The `*_with_options` functions count by the `time_semantics` of `DiffOptions` or `AddOptions`, which is `TimeSemantics::Hybrid` by default: the years, the months and the days are counted on the wall clock, and the time is counted as the exact elapsed time, as Temporal does. e.g. in a time zone which skips 02:00 to 03:00 on 2024-03-31, 2024-03-30 12:00 to 2024-03-31 12:00 is 1 day, and 2024-03-30 12:00 to 2024-03-31 11:30 is 22 hours 30 minutes. Set it to `TimeSemantics::WallClock` to count every component on the wall clock, or to `TimeSemantics::Exact` to count every component in the fixed offset of `from`.
+
+`add_date_time_diff` returns a `LocalResult` when the result falls into a gap or a fold of the time zone. `add_date_time_diff_disambiguated` resolves it by a `Disambiguation`, like Temporal's `compatible`, `earlier`, `later` and `reject`, and returns a `Result`. `try_add_date_time_diff` rejects both, and its `DiffError` also tells an overflow, a result out of the range of `chrono` and a nonexistent day of month apart.
+
+## ISO 8601 Durations
+
+`DateDiffResult` and `DateTimeDiffResult` implement `Display` and `FromStr` with ISO 8601 durations.
+
+```rust
+use date_differencer::DateTimeDiffResult;
+
+let diff: DateTimeDiffResult = "P1Y2M3DT4H5M6.000000007S".parse().unwrap();
+
+assert_eq!(1, diff.years);
+assert_eq!(7, diff.nanoseconds);
+assert_eq!("P1Y2M3DT4H5M6.000000007S", diff.to_string());
+```
+
+## Serde Support
+
+Enable the `serde` feature to derive `Serialize` and `Deserialize` for `DateDiffResult` and `DateTimeDiffResult`. They are represented as structs by default. Use `#[serde(with = "date_differencer::serde_iso8601")]` to represent them as ISO 8601 duration strings instead.
+
+## The `time` Crate
+
+The functions for `chrono` are behind the `chrono` feature, which is enabled by default. Enable the `time` feature to use the `date_differencer::time_backend` module, which has `date_diff`, `date_time_diff` and `add_date_time_diff` for `time::Date`, `time::PrimitiveDateTime` and `time::OffsetDateTime`. The `chrono` feature can be disabled by `default-features = false`.
+
+## The `jiff` Crate
+
+Enable the `jiff` feature to use the `date_differencer::jiff_backend` module, which has the same functions for `jiff::civil::Date`, `jiff::civil::DateTime` and `jiff::Zoned`. `DateTimeDiffResult` and `jiff::Span` can be converted to each other by `TryFrom`.
+
+The `time` and `jiff` crates need a newer Rust compiler than 1.62, which is the minimum supported Rust version of this crate with the `chrono` feature, so the `time` and `jiff` features are not covered by it.
+
+## `SystemTime` and Unix Timestamps
+
+Enable the `std` feature along with the `chrono` feature to use `system_time_diff`, `add_system_time_diff`, `unix_timestamp_diff` and `add_unix_timestamp_diff`, which calculate in a chosen `chrono` time zone in the same way as the functions for `DateTime`. The `std` feature does not enable any date-time library by itself, but enables the `std` feature of the enabled ones. The crate is still `no_std` without this feature.
 */
 
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+#[cfg(feature = "std")]
+extern crate std;
 
 mod constants;
 
+#[cfg(any(feature = "chrono", feature = "time", feature = "jiff"))]
 mod add_diff;
+#[cfg(feature = "chrono")]
+mod age;
+#[cfg(feature = "chrono")]
+mod anniversary;
+#[cfg(feature = "chrono")]
+mod business_day;
+#[cfg(feature = "chrono")]
+mod day_count;
 mod diff;
-
+mod iso8601;
+mod normalize;
+mod ops;
+mod options;
+#[cfg(feature = "chrono")]
+mod recurrence;
+#[cfg(feature = "chrono")]
+mod rrule;
+#[cfg(all(feature = "chrono", feature = "std"))]
+mod timestamp;
+#[cfg(feature = "chrono")]
+mod total;
+#[cfg(feature = "chrono")]
+mod working_hours;
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub mod serde_iso8601;
+
+#[cfg(feature = "jiff")]
+#[cfg_attr(docsrs, doc(cfg(feature = "jiff")))]
+pub mod jiff_backend;
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+pub mod time_backend;
+
+#[cfg(feature = "chrono")]
 pub use add_diff::*;
+#[cfg(feature = "chrono")]
+pub use age::*;
+#[cfg(feature = "chrono")]
+pub use anniversary::*;
+#[cfg(feature = "chrono")]
+pub use business_day::*;
+#[cfg(feature = "chrono")]
+pub use day_count::*;
 pub use diff::*;
+pub use iso8601::*;
+pub use options::*;
+#[cfg(feature = "chrono")]
+pub use recurrence::*;
+#[cfg(feature = "chrono")]
+pub use rrule::*;
+#[cfg(all(feature = "chrono", feature = "std"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "chrono", feature = "std"))))]
+pub use timestamp::*;
+#[cfg(feature = "chrono")]
+pub use total::*;
+#[cfg(feature = "chrono")]
+pub use working_hours::*;
@@ -0,0 +1,159 @@
+#[cfg(feature = "chrono")]
+use chrono::{prelude::*, LocalResult};
+
+#[cfg(feature = "chrono")]
+use super::{add_date_time_diff, date_time_diff};
+use super::{constants::*, DateDiffResult, DateTimeDiffResult};
+
+#[inline]
+fn is_mixed_sign(components: &[i32]) -> bool {
+    components.iter().any(|v| *v > 0) && components.iter().any(|v| *v < 0)
+}
+
+#[inline]
+fn normalize_months(years: i32, months: i32) -> Option<(i32, i32)> {
+    let total = years as i64 * 12 + months as i64;
+
+    Some((i32::try_from(total / 12).ok()?, (total % 12) as i32))
+}
+
+impl DateDiffResult {
+    /// Whether some components are positive while others are negative.
+    #[inline]
+    pub fn is_mixed_sign(&self) -> bool {
+        is_mixed_sign(&[self.years, self.months, self.days])
+    }
+
+    /// Carry months into years, e.g. 14 months becomes 1 year 2 months. Return `None` if the years overflow.
+    ///
+    /// Days are never converted to months because the number of days in a month depends on an anchor date, so the result can still be mixed-sign.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use date_differencer::DateDiffResult;
+    ///
+    /// assert_eq!(
+    ///     Some(DateDiffResult {
+    ///         years: 1, months: 2, days: 40
+    ///     }),
+    ///     DateDiffResult {
+    ///         years: 0, months: 14, days: 40
+    ///     }
+    ///     .normalize()
+    /// );
+    /// ```
+    #[inline]
+    pub fn normalize(&self) -> Option<Self> {
+        let (years, months) = normalize_months(self.years, self.months)?;
+
+        Some(DateDiffResult {
+            years,
+            months,
+            days: self.days,
+        })
+    }
+}
+
+impl DateTimeDiffResult {
+    /// Whether some components are positive while others are negative.
+    #[inline]
+    pub fn is_mixed_sign(&self) -> bool {
+        is_mixed_sign(&[
+            self.years,
+            self.months,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.nanoseconds,
+        ])
+    }
+
+    /// Carry the units with fixed lengths, i.e. nanoseconds into seconds, seconds into minutes, minutes into hours, and months into years. Return `None` if the hours or the years overflow.
+    ///
+    /// Hours are not converted to days (a day can be 23 or 25 hours long across a DST transition) and days are never converted to months, so the result can still be mixed-sign. Use `normalize_relative_to` to get the calendar decomposition from a specific start.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use date_differencer::DateTimeDiffResult;
+    ///
+    /// assert_eq!(
+    ///     Some(DateTimeDiffResult {
+    ///         years: 1,
+    ///         months: 2,
+    ///         hours: 1,
+    ///         minutes: 30,
+    ///         ..DateTimeDiffResult::default()
+    ///     }),
+    ///     DateTimeDiffResult {
+    ///         months: 14,
+    ///         minutes: 90,
+    ///         ..DateTimeDiffResult::default()
+    ///     }
+    ///     .normalize()
+    /// );
+    /// ```
+    pub fn normalize(&self) -> Option<Self> {
+        let (years, months) = normalize_months(self.years, self.months)?;
+
+        let mut nanoseconds = self.hours as i128 * HOUR_NANOSECONDS as i128
+            + self.minutes as i128 * MINUTE_NANOSECONDS as i128
+            + self.seconds as i128 * SECOND_NANOSECONDS as i128
+            + self.nanoseconds as i128;
+
+        let hours = nanoseconds / HOUR_NANOSECONDS as i128;
+        nanoseconds -= hours * HOUR_NANOSECONDS as i128;
+
+        let minutes = nanoseconds / MINUTE_NANOSECONDS as i128;
+        nanoseconds -= minutes * MINUTE_NANOSECONDS as i128;
+
+        let seconds = nanoseconds / SECOND_NANOSECONDS as i128;
+        nanoseconds -= seconds * SECOND_NANOSECONDS as i128;
+
+        Some(DateTimeDiffResult {
+            years,
+            months,
+            days: self.days,
+            hours: i32::try_from(hours).ok()?,
+            minutes: minutes as i32,
+            seconds: seconds as i32,
+            nanoseconds: nanoseconds as i32,
+        })
+    }
+
+    /// Get the canonical calendar decomposition of this difference when it starts from `anchor`, i.e. `date_time_diff(anchor, add_date_time_diff(anchor, self))`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::prelude::*;
+    /// use date_differencer::DateTimeDiffResult;
+    ///
+    /// let anchor = Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap();
+    ///
+    /// // 2021-01-31 + 1 month - 1 day -> 2021-02-27
+    /// assert_eq!(
+    ///     DateTimeDiffResult {
+    ///         days: 27,
+    ///         ..DateTimeDiffResult::default()
+    ///     },
+    ///     DateTimeDiffResult {
+    ///         months: 1,
+    ///         days: -1,
+    ///         ..DateTimeDiffResult::default()
+    ///     }
+    ///     .normalize_relative_to(anchor)
+    ///     .unwrap()
+    /// );
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[inline]
+    pub fn normalize_relative_to<Tz: TimeZone>(
+        &self,
+        anchor: DateTime<Tz>,
+    ) -> LocalResult<DateTimeDiffResult> {
+        add_date_time_diff(anchor.clone(), self).map(|to| date_time_diff(anchor.clone(), to))
+    }
+}
@@ -0,0 +1,211 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+use super::{DateDiffResult, DateTimeDiffResult};
+
+impl DateDiffResult {
+    /// Add two differences component-wise. Return `None` if any component overflows.
+    ///
+    /// No normalization is performed, e.g. 11 months + 1 month is 12 months rather than 1 year.
+    #[inline]
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(DateDiffResult {
+            years:  self.years.checked_add(rhs.years)?,
+            months: self.months.checked_add(rhs.months)?,
+            days:   self.days.checked_add(rhs.days)?,
+        })
+    }
+
+    /// Subtract a difference from another component-wise. Return `None` if any component overflows.
+    ///
+    /// No normalization is performed.
+    #[inline]
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(DateDiffResult {
+            years:  self.years.checked_sub(rhs.years)?,
+            months: self.months.checked_sub(rhs.months)?,
+            days:   self.days.checked_sub(rhs.days)?,
+        })
+    }
+
+    /// Multiply every component by `rhs`. Return `None` if any component overflows.
+    ///
+    /// No normalization is performed, e.g. 6 months × 2 is 12 months rather than 1 year.
+    #[inline]
+    pub fn checked_mul(&self, rhs: i32) -> Option<Self> {
+        Some(DateDiffResult {
+            years:  self.years.checked_mul(rhs)?,
+            months: self.months.checked_mul(rhs)?,
+            days:   self.days.checked_mul(rhs)?,
+        })
+    }
+
+    /// Negate every component. Return `None` if any component is `i32::MIN`.
+    #[inline]
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(DateDiffResult {
+            years:  self.years.checked_neg()?,
+            months: self.months.checked_neg()?,
+            days:   self.days.checked_neg()?,
+        })
+    }
+}
+
+impl DateTimeDiffResult {
+    /// Add two differences component-wise. Return `None` if any component overflows.
+    ///
+    /// No normalization is performed, e.g. 30 minutes + 30 minutes is 60 minutes rather than 1 hour.
+    #[inline]
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        Some(DateTimeDiffResult {
+            years:       self.years.checked_add(rhs.years)?,
+            months:      self.months.checked_add(rhs.months)?,
+            days:        self.days.checked_add(rhs.days)?,
+            hours:       self.hours.checked_add(rhs.hours)?,
+            minutes:     self.minutes.checked_add(rhs.minutes)?,
+            seconds:     self.seconds.checked_add(rhs.seconds)?,
+            nanoseconds: self.nanoseconds.checked_add(rhs.nanoseconds)?,
+        })
+    }
+
+    /// Subtract a difference from another component-wise. Return `None` if any component overflows.
+    ///
+    /// No normalization is performed.
+    #[inline]
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        Some(DateTimeDiffResult {
+            years:       self.years.checked_sub(rhs.years)?,
+            months:      self.months.checked_sub(rhs.months)?,
+            days:        self.days.checked_sub(rhs.days)?,
+            hours:       self.hours.checked_sub(rhs.hours)?,
+            minutes:     self.minutes.checked_sub(rhs.minutes)?,
+            seconds:     self.seconds.checked_sub(rhs.seconds)?,
+            nanoseconds: self.nanoseconds.checked_sub(rhs.nanoseconds)?,
+        })
+    }
+
+    /// Multiply every component by `rhs`. Return `None` if any component overflows.
+    ///
+    /// No normalization is performed, e.g. 40 minutes × 3 is 120 minutes rather than 2 hours.
+    #[inline]
+    pub fn checked_mul(&self, rhs: i32) -> Option<Self> {
+        Some(DateTimeDiffResult {
+            years:       self.years.checked_mul(rhs)?,
+            months:      self.months.checked_mul(rhs)?,
+            days:        self.days.checked_mul(rhs)?,
+            hours:       self.hours.checked_mul(rhs)?,
+            minutes:     self.minutes.checked_mul(rhs)?,
+            seconds:     self.seconds.checked_mul(rhs)?,
+            nanoseconds: self.nanoseconds.checked_mul(rhs)?,
+        })
+    }
+
+    /// Negate every component. Return `None` if any component is `i32::MIN`.
+    #[inline]
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(DateTimeDiffResult {
+            years:       self.years.checked_neg()?,
+            months:      self.months.checked_neg()?,
+            days:        self.days.checked_neg()?,
+            hours:       self.hours.checked_neg()?,
+            minutes:     self.minutes.checked_neg()?,
+            seconds:     self.seconds.checked_neg()?,
+            nanoseconds: self.nanoseconds.checked_neg()?,
+        })
+    }
+}
+
+impl Neg for DateDiffResult {
+    type Output = DateDiffResult;
+
+    /// # Panics
+    ///
+    /// Panics if any component is `i32::MIN`. Use `checked_neg` to handle it.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("overflow when negating a DateDiffResult")
+    }
+}
+
+impl Add for DateDiffResult {
+    type Output = DateDiffResult;
+
+    /// # Panics
+    ///
+    /// Panics if any component overflows. Use `checked_add` to handle it.
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("overflow when adding DateDiffResults")
+    }
+}
+
+impl Sub for DateDiffResult {
+    type Output = DateDiffResult;
+
+    /// # Panics
+    ///
+    /// Panics if any component overflows. Use `checked_sub` to handle it.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("overflow when subtracting DateDiffResults")
+    }
+}
+
+impl Mul<i32> for DateDiffResult {
+    type Output = DateDiffResult;
+
+    /// # Panics
+    ///
+    /// Panics if any component overflows. Use `checked_mul` to handle it.
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.checked_mul(rhs).expect("overflow when multiplying a DateDiffResult")
+    }
+}
+
+impl Neg for DateTimeDiffResult {
+    type Output = DateTimeDiffResult;
+
+    /// # Panics
+    ///
+    /// Panics if any component is `i32::MIN`. Use `checked_neg` to handle it.
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.checked_neg().expect("overflow when negating a DateTimeDiffResult")
+    }
+}
+
+impl Add for DateTimeDiffResult {
+    type Output = DateTimeDiffResult;
+
+    /// # Panics
+    ///
+    /// Panics if any component overflows. Use `checked_add` to handle it.
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("overflow when adding DateTimeDiffResults")
+    }
+}
+
+impl Sub for DateTimeDiffResult {
+    type Output = DateTimeDiffResult;
+
+    /// # Panics
+    ///
+    /// Panics if any component overflows. Use `checked_sub` to handle it.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("overflow when subtracting DateTimeDiffResults")
+    }
+}
+
+impl Mul<i32> for DateTimeDiffResult {
+    type Output = DateTimeDiffResult;
+
+    /// # Panics
+    ///
+    /// Panics if any component overflows. Use `checked_mul` to handle it.
+    #[inline]
+    fn mul(self, rhs: i32) -> Self::Output {
+        self.checked_mul(rhs).expect("overflow when multiplying a DateTimeDiffResult")
+    }
+}
@@ -0,0 +1,112 @@
+/// Units of a date-time difference, ordered from the smallest to the largest.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Unit {
+    Nanoseconds,
+    Seconds,
+    Minutes,
+    Hours,
+    Days,
+    Weeks,
+    Months,
+    Years,
+}
+
+/// Rounding modes for the smallest unit of a date-time difference.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum RoundingMode {
+    /// Round towards zero.
+    #[default]
+    Trunc,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest, and ties away from zero.
+    HalfExpand,
+    /// Round to the nearest, and ties to the even increment.
+    HalfEven,
+}
+
+/// What to do when the day of month does not exist after adding years or months, e.g. 2021-01-31 + 1 month.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum EndOfMonth {
+    /// Clamp the day to the last day of the month, e.g. 2021-01-31 + 1 month is 2021-02-28.
+    #[default]
+    Clamp,
+    /// Overflow the extra days into the next month, e.g. 2021-01-31 + 1 month is 2021-03-03.
+    Overflow,
+    /// Fail, e.g. 2021-01-31 + 1 month has no result.
+    Reject,
+    /// Clamp the day, and also move the last day of a month to the last day of the result month, e.g. 2021-02-28 + 1 month is 2021-03-31.
+    StickToMonthEnd,
+}
+
+/// How the time is counted between two `DateTime` instances whose offsets differ, e.g. across a DST transition.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum TimeSemantics {
+    /// Count the years, the months and the days on the wall clock, and the rest as the exact elapsed time from the date-time they reach, as Temporal does. e.g. in a time zone which skips 02:00 to 03:00 on 2024-03-31, 2024-03-30 12:00 to 2024-03-31 12:00 is 1 day, and 2024-03-30 12:00 to 2024-03-31 11:30 is 22 hours 30 minutes. If the largest unit is smaller than days, the result is the exact elapsed time, e.g. 2024-03-30 12:00 to 2024-03-31 12:00 is 23 hours.
+    #[default]
+    Hybrid,
+    /// Count every component on the wall clock, so 24 elapsed hours can be 23 hours or 1 day 1 hour across a DST transition.
+    WallClock,
+    /// Count every component on the fixed offset of `from`, so a day is always 24 elapsed hours.
+    Exact,
+}
+
+/// How a wall-clock date-time which does not exist or is ambiguous in a time zone, e.g. across a DST transition, is resolved, as Temporal does.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum Disambiguation {
+    /// Use the earlier one of an ambiguous date-time, and move a date-time in a gap forward by the length of the gap, e.g. 02:30 is 03:30 if 02:00 to 03:00 is skipped.
+    #[default]
+    Compatible,
+    /// Use the earlier one of an ambiguous date-time, and move a date-time in a gap backward by the length of the gap, e.g. 02:30 is 01:30 if 02:00 to 03:00 is skipped.
+    Earlier,
+    /// Use the later one of an ambiguous date-time, and move a date-time in a gap forward by the length of the gap.
+    Later,
+    /// Fail for an ambiguous date-time or a date-time in a gap.
+    Reject,
+}
+
+/// Options of the `add_date_time_diff_with_options` function.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AddOptions {
+    /// What to do when the day of month does not exist after adding the years and the months. The default value is `EndOfMonth::Clamp`.
+    pub end_of_month:   EndOfMonth,
+    /// How the time is added. With `TimeSemantics::Hybrid`, the years, the months and the days are added on the wall clock, and the rest is added as the exact elapsed time. The default value is `TimeSemantics::Hybrid`.
+    pub time_semantics: TimeSemantics,
+}
+
+/// Options of the `date_time_diff_with_options` and `date_time_weeks_diff_with_options` functions.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DiffOptions {
+    /// The largest unit of the result. Units above it are folded into it, e.g. 1 year 2 months becomes 14 months if it is `Unit::Months`. The default value is `Unit::Years`.
+    pub largest_unit:       Unit,
+    /// The smallest unit of the result. Units below it are rounded off by `rounding_mode`. The default value is `Unit::Nanoseconds`.
+    ///
+    /// If it is larger than `largest_unit`, `largest_unit` is raised to it.
+    pub smallest_unit:      Unit,
+    /// How to round off the units below `smallest_unit`. The default value is `RoundingMode::Trunc`.
+    pub rounding_mode:      RoundingMode,
+    /// Round the `smallest_unit` component to a multiple of this value, e.g. 15 with `Unit::Minutes` for the nearest quarter-hour. It should evenly divide the next larger unit unless `smallest_unit` is also the largest unit. `0` is treated as `1`. The default value is `1`.
+    pub rounding_increment: u32,
+    /// The end-of-month policy that the years and the months are counted with, so that `from` + the result is `to` under the same policy. The default value is `EndOfMonth::Clamp`.
+    ///
+    /// With `EndOfMonth::Reject`, the months are only counted up to a date which exists, e.g. 2021-01-31 to 2021-03-05 is 33 days.
+    pub end_of_month:       EndOfMonth,
+    /// How the time between `from` and `to` is counted when their offsets differ. The default value is `TimeSemantics::Hybrid`.
+    pub time_semantics:     TimeSemantics,
+}
+
+impl Default for DiffOptions {
+    #[inline]
+    fn default() -> Self {
+        DiffOptions {
+            largest_unit:       Unit::Years,
+            smallest_unit:      Unit::Nanoseconds,
+            rounding_mode:      RoundingMode::Trunc,
+            rounding_increment: 1,
+            end_of_month:       EndOfMonth::Clamp,
+            time_semantics:     TimeSemantics::Hybrid,
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use core::iter::FusedIterator;
+
+use chrono::{prelude::*, LocalResult};
+
+use super::{
+    add_date_time_diff_with_options, AddOptions, DateTimeDiff, DateTimeWeeksDiffResult, EndOfMonth,
+};
+
+/// Options of the `recurrence_with_options` function.
+#[derive(Debug, Clone)]
+pub struct RecurrenceOptions<Tz: TimeZone> {
+    /// The last date-time which can be yielded (inclusive). For a negative step, it is the earliest one. Nonexistent occurrences cannot be compared, so they never end the iteration. The default value is `None`, i.e. unbounded.
+    pub until:        Option<DateTime<Tz>>,
+    /// The maximum number of occurrences to yield, including the nonexistent and ambiguous ones. The default value is `None`, i.e. unbounded.
+    pub count:        Option<u32>,
+    /// What to do when the day of month does not exist. The default value is `EndOfMonth::Clamp`.
+    pub end_of_month: EndOfMonth,
+}
+
+impl<Tz: TimeZone> Default for RecurrenceOptions<Tz> {
+    #[inline]
+    fn default() -> Self {
+        RecurrenceOptions {
+            until:        None,
+            count:        None,
+            end_of_month: EndOfMonth::Clamp,
+        }
+    }
+}
+
+/// An iterator of `start` + n × `step` for n = 0, 1, 2, ...
+///
+/// Every occurrence is calculated from `start`, so the clamped days of month do not drift, e.g. 2024-01-31 + 2 months is 2024-03-31 rather than 2024-02-29 + 1 month. The iteration ends when n × `step` overflows.
+#[derive(Debug, Clone)]
+pub struct Recurrence<Tz: TimeZone> {
+    start:    DateTime<Tz>,
+    step:     DateTimeWeeksDiffResult,
+    options:  RecurrenceOptions<Tz>,
+    // whether the occurrences go backwards, so that `until` is a lower bound
+    negative: bool,
+    n:        i32,
+    done:     bool,
+}
+
+impl<Tz: TimeZone> Recurrence<Tz> {
+    #[inline]
+    fn occurrence(&self, n: i32) -> Option<DateTimeWeeksDiffResult> {
+        let step = &self.step;
+
+        Some(DateTimeWeeksDiffResult {
+            years:       step.years.checked_mul(n)?,
+            months:      step.months.checked_mul(n)?,
+            weeks:       step.weeks.checked_mul(n)?,
+            days:        step.days.checked_mul(n)?,
+            hours:       step.hours.checked_mul(n)?,
+            minutes:     step.minutes.checked_mul(n)?,
+            seconds:     step.seconds.checked_mul(n)?,
+            nanoseconds: step.nanoseconds.checked_mul(n)?,
+        })
+    }
+
+    #[inline]
+    fn is_beyond(&self, date_time: &DateTime<Tz>) -> bool {
+        match &self.options.until {
+            Some(until) => {
+                if self.negative {
+                    date_time < until
+                } else {
+                    date_time > until
+                }
+            },
+            None => false,
+        }
+    }
+}
+
+impl<Tz: TimeZone> Iterator for Recurrence<Tz> {
+    type Item = LocalResult<DateTime<Tz>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if let Some(count) = self.options.count {
+            if self.n as u32 >= count {
+                self.done = true;
+
+                return None;
+            }
+        }
+
+        let occurrence = match self.occurrence(self.n) {
+            Some(v) => v,
+            None => {
+                self.done = true;
+
+                return None;
+            },
+        };
+
+        let result =
+            add_date_time_diff_with_options(self.start.clone(), &occurrence, &AddOptions {
+                end_of_month: self.options.end_of_month,
+                ..AddOptions::default()
+            });
+
+        // the one of an ambiguous occurrence which comes first in the direction decides whether the iteration ends
+        let beyond = match &result {
+            LocalResult::Single(v) => self.is_beyond(v),
+            LocalResult::Ambiguous(a, b) => self.is_beyond(if self.negative { b } else { a }),
+            LocalResult::None => false,
+        };
+
+        if beyond {
+            self.done = true;
+
+            return None;
+        }
+
+        match self.n.checked_add(1) {
+            Some(n) => self.n = n,
+            None => self.done = true,
+        }
+
+        Some(result)
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for Recurrence<Tz> {}
+
+/// Create an iterator of `start` + n × `step` for n = 0, 1, 2, ... without limits.
+///
+/// Each item is a `LocalResult` because an occurrence can be ambiguous or nonexistent in the time zone of `start`.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{recurrence, DateTimeDiffResult};
+///
+/// let start = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+///
+/// let occurrences: Vec<_> = recurrence(start, &DateTimeDiffResult {
+///     months: 1,
+///     ..DateTimeDiffResult::default()
+/// })
+/// .take(3)
+/// .map(|v| v.unwrap())
+/// .collect();
+///
+/// assert_eq!(
+///     vec![
+///         Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap(),
+///     ],
+///     occurrences
+/// );
+/// ```
+#[inline]
+pub fn recurrence<Tz: TimeZone>(start: DateTime<Tz>, step: &dyn DateTimeDiff) -> Recurrence<Tz> {
+    recurrence_with_options(start, step, RecurrenceOptions::default())
+}
+
+/// Create an iterator of `start` + n × `step` for n = 0, 1, 2, ... with the `until` and `count` limits.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{
+///     recurrence_with_options, DateTimeDiffResult, RecurrenceOptions,
+/// };
+///
+/// let start = Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap();
+///
+/// let occurrences: Vec<_> = recurrence_with_options(
+///     start,
+///     &DateTimeDiffResult {
+///         months: 1,
+///         days: 15,
+///         ..DateTimeDiffResult::default()
+///     },
+///     RecurrenceOptions {
+///         until: Some(Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap()),
+///         ..RecurrenceOptions::default()
+///     },
+/// )
+/// .map(|v| v.unwrap())
+/// .collect();
+///
+/// assert_eq!(
+///     vec![
+///         Utc.with_ymd_and_hms(2024, 1, 31, 0, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2024, 3, 15, 0, 0, 0).unwrap(),
+///         Utc.with_ymd_and_hms(2024, 4, 30, 0, 0, 0).unwrap(),
+///     ],
+///     occurrences
+/// );
+/// ```
+pub fn recurrence_with_options<Tz: TimeZone>(
+    start: DateTime<Tz>,
+    step: &dyn DateTimeDiff,
+    options: RecurrenceOptions<Tz>,
+) -> Recurrence<Tz> {
+    let step = DateTimeWeeksDiffResult {
+        years:       step.years(),
+        months:      step.months(),
+        weeks:       step.weeks(),
+        days:        step.days(),
+        hours:       step.hours(),
+        minutes:     step.minutes(),
+        seconds:     step.seconds(),
+        nanoseconds: step.nanoseconds(),
+    };
+
+    // the direction of `until` is the sign of the largest nonzero component
+    let negative = [
+        step.years,
+        step.months,
+        step.weeks,
+        step.days,
+        step.hours,
+        step.minutes,
+        step.seconds,
+        step.nanoseconds,
+    ]
+    .into_iter()
+    .find(|v| *v != 0)
+    .map_or(false, |v| v < 0);
+
+    Recurrence {
+        start,
+        step,
+        options,
+        negative,
+        n: 0,
+        done: false,
+    }
+}
@@ -0,0 +1,625 @@
+use core::{
+    fmt::{self, Display, Formatter},
+    iter::FusedIterator,
+    str::FromStr,
+};
+
+use chrono::{prelude::*, Duration};
+
+use super::{add_date_time_diff, add_diff::_disambiguate, DateDiffResult, Disambiguation};
+
+/// Errors that can occur when parsing an RFC 5545 recurrence rule.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ParseRRuleError {
+    /// The input is not a well-formed recurrence rule, e.g. it has no `FREQ`, or has both `COUNT` and `UNTIL`.
+    InvalidFormat,
+    /// A value is out of its range, e.g. `BYMONTH=13`.
+    OutOfRange,
+    /// The rule part or the frequency is valid in RFC 5545 but not supported, e.g. `BYHOUR` or `FREQ=HOURLY`.
+    Unsupported,
+}
+
+impl Display for ParseRRuleError {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseRRuleError::InvalidFormat => f.write_str("invalid recurrence rule"),
+            ParseRRuleError::OutOfRange => f.write_str("recurrence rule value out of range"),
+            ParseRRuleError::Unsupported => f.write_str("unsupported recurrence rule part"),
+        }
+    }
+}
+
+/// The `FREQ` of a recurrence rule.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Frequency {
+    Yearly,
+    Monthly,
+    Weekly,
+    Daily,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+enum Until {
+    Date(NaiveDate),
+    Local(NaiveDateTime),
+    Utc(NaiveDateTime),
+}
+
+/// A subset of the RFC 5545 recurrence rule (`FREQ`, `INTERVAL`, `COUNT`, `UNTIL`, `BYMONTH`, `BYMONTHDAY`, `BYDAY`, `BYSETPOS` and `WKST`), parsed from a string such as `FREQ=MONTHLY;BYDAY=-1FR;COUNT=3`.
+///
+/// The frequencies below a day and the `BYYEARDAY`, `BYWEEKNO`, `BYHOUR`, `BYMINUTE` and `BYSECOND` rule parts are not supported.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct RRule {
+    frequency:    Frequency,
+    interval:     u32,
+    count:        Option<u32>,
+    until:        Option<Until>,
+    // bit m for month m
+    by_month:     u16,
+    // bit d for day d, bit 31 + d for day -d
+    by_month_day: u64,
+    // for each weekday from Monday, bit 0 for every one, bit n for the n-th one, bit 53 + n for the n-th last one
+    by_day:       [u128; 7],
+    // bit 366 + n for the n-th occurrence in a period
+    by_set_pos:   [u64; 12],
+    week_start:   Weekday,
+}
+
+#[inline]
+fn parse_integer(s: &str) -> Result<i32, ParseRRuleError> {
+    // at most one sign, and `i32::from_str` accepts the sign it is given
+    let digits = s.strip_prefix(|c| c == '+' || c == '-').unwrap_or(s);
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseRRuleError::InvalidFormat);
+    }
+
+    s.parse().map_err(|_| ParseRRuleError::OutOfRange)
+}
+
+#[inline]
+fn parse_weekday(s: &str) -> Result<Weekday, ParseRRuleError> {
+    const WEEKDAYS: [(&str, Weekday); 7] = [
+        ("MO", Weekday::Mon),
+        ("TU", Weekday::Tue),
+        ("WE", Weekday::Wed),
+        ("TH", Weekday::Thu),
+        ("FR", Weekday::Fri),
+        ("SA", Weekday::Sat),
+        ("SU", Weekday::Sun),
+    ];
+
+    WEEKDAYS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(s))
+        .map(|(_, weekday)| *weekday)
+        .ok_or(ParseRRuleError::InvalidFormat)
+}
+
+#[inline]
+fn parse_frequency(s: &str) -> Result<Frequency, ParseRRuleError> {
+    if let Some((_, frequency)) = FREQUENCIES.iter().find(|(name, _)| name.eq_ignore_ascii_case(s))
+    {
+        return Ok(*frequency);
+    }
+
+    if UNSUPPORTED_FREQUENCIES.iter().any(|v| v.eq_ignore_ascii_case(s)) {
+        Err(ParseRRuleError::Unsupported)
+    } else {
+        Err(ParseRRuleError::InvalidFormat)
+    }
+}
+
+fn parse_until(s: &str) -> Result<Until, ParseRRuleError> {
+    let bytes = s.as_bytes();
+
+    let number = |range: core::ops::Range<usize>| -> Result<u32, ParseRRuleError> {
+        let digits = bytes.get(range).ok_or(ParseRRuleError::InvalidFormat)?;
+
+        if !digits.iter().all(u8::is_ascii_digit) {
+            return Err(ParseRRuleError::InvalidFormat);
+        }
+
+        Ok(digits.iter().fold(0, |v, digit| v * 10 + (digit - b'0') as u32))
+    };
+
+    let date = NaiveDate::from_ymd_opt(number(0..4)? as i32, number(4..6)?, number(6..8)?)
+        .ok_or(ParseRRuleError::OutOfRange)?;
+
+    match bytes.len() {
+        8 => return Ok(Until::Date(date)),
+        15 | 16 if bytes[8] == b'T' => (),
+        _ => return Err(ParseRRuleError::InvalidFormat),
+    }
+
+    let date_time = date
+        .and_hms_opt(number(9..11)?, number(11..13)?, number(13..15)?)
+        .ok_or(ParseRRuleError::OutOfRange)?;
+
+    match bytes.get(15) {
+        None => Ok(Until::Local(date_time)),
+        Some(b'Z') => Ok(Until::Utc(date_time)),
+        Some(_) => Err(ParseRRuleError::InvalidFormat),
+    }
+}
+
+// the index of a rule part is its bit to check duplicates
+const PARTS: [&str; 8] =
+    ["FREQ", "INTERVAL", "COUNT", "UNTIL", "BYMONTH", "BYMONTHDAY", "BYDAY", "BYSETPOS"];
+
+const UNSUPPORTED_PARTS: [&str; 5] = ["BYSECOND", "BYMINUTE", "BYHOUR", "BYYEARDAY", "BYWEEKNO"];
+
+const FREQUENCIES: [(&str, Frequency); 4] = [
+    ("YEARLY", Frequency::Yearly),
+    ("MONTHLY", Frequency::Monthly),
+    ("WEEKLY", Frequency::Weekly),
+    ("DAILY", Frequency::Daily),
+];
+
+const UNSUPPORTED_FREQUENCIES: [&str; 3] = ["HOURLY", "MINUTELY", "SECONDLY"];
+
+impl FromStr for RRule {
+    type Err = ParseRRuleError;
+
+    /// Parse a recurrence rule, e.g. `FREQ=MONTHLY;BYDAY=-1FR;COUNT=3`. The `RRULE:` prefix is optional, and the names are case-insensitive.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = match s.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &s[6..],
+            _ => s,
+        };
+
+        let mut frequency = None;
+        let mut rule = RRule {
+            frequency:    Frequency::Daily,
+            interval:     1,
+            count:        None,
+            until:        None,
+            by_month:     0,
+            by_month_day: 0,
+            by_day:       [0; 7],
+            by_set_pos:   [0; 12],
+            week_start:   Weekday::Mon,
+        };
+
+        // to reject the duplicated rule parts
+        let mut seen: u8 = 0;
+        let mut has_ordinal = false;
+
+        for part in s.split(';') {
+            let (name, value) = part.split_once('=').ok_or(ParseRRuleError::InvalidFormat)?;
+
+            if value.is_empty() {
+                return Err(ParseRRuleError::InvalidFormat);
+            }
+
+            let index = match PARTS.iter().position(|v| v.eq_ignore_ascii_case(name)) {
+                Some(v) => v,
+                None if name.eq_ignore_ascii_case("WKST") => {
+                    rule.week_start = parse_weekday(value)?;
+
+                    continue;
+                },
+                None if UNSUPPORTED_PARTS.iter().any(|v| v.eq_ignore_ascii_case(name)) => {
+                    return Err(ParseRRuleError::Unsupported)
+                },
+                None => return Err(ParseRRuleError::InvalidFormat),
+            };
+
+            if seen & (1 << index) != 0 {
+                return Err(ParseRRuleError::InvalidFormat);
+            }
+
+            seen |= 1 << index;
+
+            match index {
+                0 => frequency = Some(parse_frequency(value)?),
+                1 => {
+                    rule.interval = u32::try_from(parse_integer(value)?)
+                        .ok()
+                        .filter(|v| *v > 0)
+                        .ok_or(ParseRRuleError::OutOfRange)?
+                },
+                2 => {
+                    rule.count = Some(
+                        u32::try_from(parse_integer(value)?)
+                            .map_err(|_| ParseRRuleError::OutOfRange)?,
+                    )
+                },
+                3 => rule.until = Some(parse_until(value)?),
+                _ => {
+                    for item in value.split(',') {
+                        match index {
+                            4 => {
+                                let month = parse_integer(item)?;
+
+                                if !(1..=12).contains(&month) {
+                                    return Err(ParseRRuleError::OutOfRange);
+                                }
+
+                                rule.by_month |= 1 << month;
+                            },
+                            5 => {
+                                let day = parse_integer(item)?;
+
+                                rule.by_month_day |= match day {
+                                    1..=31 => 1 << day,
+                                    -31..=-1 => 1 << (31 - day),
+                                    _ => return Err(ParseRRuleError::OutOfRange),
+                                };
+                            },
+                            6 => {
+                                let split = item
+                                    .len()
+                                    .checked_sub(2)
+                                    .ok_or(ParseRRuleError::InvalidFormat)?;
+                                let (ordinal, weekday) = (item.get(..split), item.get(split..));
+
+                                let weekday =
+                                    parse_weekday(weekday.ok_or(ParseRRuleError::InvalidFormat)?)?;
+
+                                let bit = match ordinal.ok_or(ParseRRuleError::InvalidFormat)? {
+                                    "" => 0,
+                                    ordinal => {
+                                        has_ordinal = true;
+
+                                        match parse_integer(ordinal)? {
+                                            n @ 1..=53 => n,
+                                            n @ -53..=-1 => 53 - n,
+                                            _ => return Err(ParseRRuleError::OutOfRange),
+                                        }
+                                    },
+                                };
+
+                                rule.by_day[weekday.num_days_from_monday() as usize] |= 1 << bit;
+                            },
+                            7 => {
+                                let position = parse_integer(item)?;
+
+                                if position == 0 || !(-366..=366).contains(&position) {
+                                    return Err(ParseRRuleError::OutOfRange);
+                                }
+
+                                let bit = (366 + position) as usize;
+
+                                rule.by_set_pos[bit / 64] |= 1 << (bit % 64);
+                            },
+                            _ => unreachable!(),
+                        }
+                    }
+                },
+            }
+        }
+
+        rule.frequency = frequency.ok_or(ParseRRuleError::InvalidFormat)?;
+
+        let invalid = (rule.count.is_some() && rule.until.is_some())
+            // BYSETPOS only selects among the other rule parts
+            || (rule.by_set_pos != [0; 12] && seen & 0b111_0000 == 0)
+            || (rule.frequency == Frequency::Weekly && rule.by_month_day != 0)
+            || (has_ordinal && matches!(rule.frequency, Frequency::Weekly | Frequency::Daily));
+
+        if invalid {
+            return Err(ParseRRuleError::InvalidFormat);
+        }
+
+        Ok(rule)
+    }
+}
+
+impl RRule {
+    /// The `FREQ` of this rule.
+    #[inline]
+    pub fn frequency(&self) -> Frequency {
+        self.frequency
+    }
+
+    /// The `INTERVAL` of this rule. The default value is `1`.
+    #[inline]
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// The `COUNT` of this rule.
+    #[inline]
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Expand this rule from `dtstart` into an iterator of the occurrences. The time of day of every occurrence is the same as `dtstart` on the wall clock.
+    ///
+    /// `dtstart` fills the rule parts which are omitted, e.g. `FREQ=MONTHLY` recurs on the day of month of `dtstart`. The dates which do not exist, e.g. the 31st in a short month, are skipped rather than clamped as RFC 5545 requires.
+    ///
+    /// An occurrence whose time of day does not exist or is ambiguous in the time zone of `dtstart` is resolved as RFC 5545 section 3.3.5 does, i.e. with `Disambiguation::Compatible`. A time in a gap is interpreted with the offset before the gap, which moves it forward by the length of the gap, and a time in a fold is the first of the two.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::prelude::*;
+    /// use date_differencer::RRule;
+    ///
+    /// let rule: RRule = "FREQ=MONTHLY;COUNT=4".parse().unwrap();
+    /// let dtstart = Utc.with_ymd_and_hms(2007, 1, 31, 9, 0, 0).unwrap();
+    ///
+    /// let occurrences: Vec<_> = rule.occurrences(dtstart).collect();
+    ///
+    /// assert_eq!(
+    ///     vec![
+    ///         Utc.with_ymd_and_hms(2007, 1, 31, 9, 0, 0).unwrap(),
+    ///         Utc.with_ymd_and_hms(2007, 3, 31, 9, 0, 0).unwrap(),
+    ///         Utc.with_ymd_and_hms(2007, 5, 31, 9, 0, 0).unwrap(),
+    ///         Utc.with_ymd_and_hms(2007, 7, 31, 9, 0, 0).unwrap(),
+    ///     ],
+    ///     occurrences
+    /// );
+    /// ```
+    pub fn occurrences<Tz: TimeZone>(&self, dtstart: DateTime<Tz>) -> RRuleOccurrences<Tz> {
+        let mut rule = *self;
+
+        let start = dtstart.naive_local();
+
+        if rule.by_month_day == 0 && rule.by_day == [0; 7] {
+            match rule.frequency {
+                Frequency::Yearly => {
+                    if rule.by_month == 0 {
+                        rule.by_month = 1 << start.month();
+                    }
+
+                    rule.by_month_day = 1 << start.day();
+                },
+                Frequency::Monthly => rule.by_month_day = 1 << start.day(),
+                Frequency::Weekly => {
+                    rule.by_day[start.weekday().num_days_from_monday() as usize] = 1
+                },
+                Frequency::Daily => (),
+            }
+        }
+
+        let first_period = match rule.frequency {
+            Frequency::Yearly => NaiveDate::from_ymd_opt(start.year(), 1, 1).unwrap(),
+            Frequency::Monthly => NaiveDate::from_ymd_opt(start.year(), start.month(), 1).unwrap(),
+            Frequency::Weekly => {
+                let days = (start.weekday().num_days_from_monday() + 7
+                    - rule.week_start.num_days_from_monday())
+                    % 7;
+
+                start.date() - Duration::days(days as i64)
+            },
+            Frequency::Daily => start.date(),
+        };
+
+        RRuleOccurrences {
+            rule,
+            dtstart,
+            start,
+            first_period,
+            period: -1,
+            period_start: first_period,
+            period_days: 0,
+            period_matches: 0,
+            cursor: 0,
+            matched: 0,
+            empty_periods: 0,
+            yielded: 0,
+            done: false,
+        }
+    }
+
+    fn matches(&self, date: NaiveDate) -> bool {
+        let (year, month, day) = (date.year(), date.month(), date.day());
+
+        if self.by_month != 0 && self.by_month & (1 << month) == 0 {
+            return false;
+        }
+
+        let days_in_month = year_helper::get_days_in_month(year, month as u8).unwrap() as u32;
+
+        if self.by_month_day != 0
+            && self.by_month_day & ((1 << day) | (1 << (31 + days_in_month + 1 - day))) == 0
+        {
+            return false;
+        }
+
+        if self.by_day != [0; 7] {
+            let by_day = self.by_day[date.weekday().num_days_from_monday() as usize];
+
+            if by_day & 1 == 0 {
+                // the ordinals are counted in the year only for a yearly rule without BYMONTH
+                let (index, length) = if self.frequency == Frequency::Yearly && self.by_month == 0 {
+                    (date.ordinal0(), year_helper::get_days_in_year(year) as u32)
+                } else {
+                    (day - 1, days_in_month)
+                };
+
+                let n = index / 7 + 1;
+                let n_last = (length - 1 - index) / 7 + 1;
+
+                if by_day & ((1 << n) | (1 << (53 + n_last))) == 0 {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    #[inline]
+    fn has_set_pos(&self, position: i32) -> bool {
+        let bit = (366 + position) as usize;
+
+        (0..=732).contains(&bit) && self.by_set_pos[bit / 64] & (1 << (bit % 64)) != 0
+    }
+}
+
+/// An iterator of the occurrences of an `RRule`. Create it by `RRule::occurrences`.
+#[derive(Debug, Clone)]
+pub struct RRuleOccurrences<Tz: TimeZone> {
+    rule:           RRule,
+    dtstart:        DateTime<Tz>,
+    start:          NaiveDateTime,
+    first_period:   NaiveDate,
+    period:         i32,
+    period_start:   NaiveDate,
+    period_days:    u32,
+    // the number of matched dates in the current period, for the negative BYSETPOS
+    period_matches: u32,
+    cursor:         u32,
+    matched:        u32,
+    empty_periods:  u32,
+    yielded:        u32,
+    done:           bool,
+}
+
+impl<Tz: TimeZone> RRuleOccurrences<Tz> {
+    // move to the next period by `add_date_time_diff` from the first one, so that the periods never drift
+    fn next_period(&mut self) -> Option<()> {
+        if self.period >= 0 && self.matched == 0 {
+            self.empty_periods += 1;
+        } else {
+            self.empty_periods = 0;
+        }
+
+        // the Gregorian calendar repeats every 400 years, so a rule which has not matched in such a cycle never will
+        let cycle = match self.rule.frequency {
+            Frequency::Yearly => 400,
+            Frequency::Monthly => 4800,
+            Frequency::Weekly => 20871,
+            Frequency::Daily => 146097,
+        };
+
+        if self.empty_periods > cycle {
+            return None;
+        }
+
+        self.period = self.period.checked_add(1)?;
+
+        let n = self.period.checked_mul(self.rule.interval as i32)?;
+
+        let diff = match self.rule.frequency {
+            Frequency::Yearly => DateDiffResult {
+                years: n,
+                ..DateDiffResult::default()
+            },
+            Frequency::Monthly => DateDiffResult {
+                months: n,
+                ..DateDiffResult::default()
+            },
+            Frequency::Weekly => DateDiffResult {
+                days: n.checked_mul(7)?,
+                ..DateDiffResult::default()
+            },
+            Frequency::Daily => DateDiffResult {
+                days: n,
+                ..DateDiffResult::default()
+            },
+        };
+
+        let anchor = Utc.from_utc_datetime(&self.first_period.and_hms_opt(0, 0, 0)?);
+
+        self.period_start = add_date_time_diff(anchor, &diff).single()?.date_naive();
+
+        let (year, month) = (self.period_start.year(), self.period_start.month());
+
+        self.period_days = match self.rule.frequency {
+            Frequency::Yearly => year_helper::get_days_in_year(year) as u32,
+            Frequency::Monthly => year_helper::get_days_in_month(year, month as u8).unwrap() as u32,
+            Frequency::Weekly => 7,
+            Frequency::Daily => 1,
+        };
+
+        self.cursor = 0;
+        self.matched = 0;
+
+        if self.rule.by_set_pos != [0; 12] {
+            self.period_matches = (0..self.period_days)
+                .filter(|i| self.rule.matches(self.period_start + Duration::days(*i as i64)))
+                .count() as u32;
+        }
+
+        Some(())
+    }
+
+    #[inline]
+    fn is_beyond_until(&self, date_time: &NaiveDateTime, result: &DateTime<Tz>) -> bool {
+        match self.rule.until {
+            Some(Until::Date(until)) => date_time.date() > until,
+            Some(Until::Local(until)) => *date_time > until,
+            Some(Until::Utc(until)) => result.naive_utc() > until,
+            None => false,
+        }
+    }
+}
+
+impl<Tz: TimeZone> Iterator for RRuleOccurrences<Tz> {
+    type Item = DateTime<Tz>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.done {
+                return None;
+            }
+
+            if self.rule.count.map_or(false, |count| self.yielded >= count) {
+                self.done = true;
+
+                return None;
+            }
+
+            if self.cursor >= self.period_days {
+                if self.next_period().is_none() {
+                    self.done = true;
+                }
+
+                continue;
+            }
+
+            let date = self.period_start + Duration::days(self.cursor as i64);
+
+            self.cursor += 1;
+
+            if !self.rule.matches(date) {
+                continue;
+            }
+
+            self.matched += 1;
+
+            if self.rule.by_set_pos != [0; 12]
+                && !self.rule.has_set_pos(self.matched as i32)
+                && !self.rule.has_set_pos(self.matched as i32 - self.period_matches as i32 - 1)
+            {
+                continue;
+            }
+
+            let date_time = date.and_time(self.start.time());
+
+            if date_time < self.start {
+                continue;
+            }
+
+            let result = match _disambiguate(
+                &self.dtstart.timezone(),
+                &date_time,
+                Disambiguation::Compatible,
+            ) {
+                Ok(v) => v,
+                Err(_) => {
+                    self.done = true;
+
+                    return None;
+                },
+            };
+
+            if self.is_beyond_until(&date_time, &result) {
+                self.done = true;
+
+                return None;
+            }
+
+            self.yielded += 1;
+
+            return Some(result);
+        }
+    }
+}
+
+impl<Tz: TimeZone> FusedIterator for RRuleOccurrences<Tz> {}
@@ -0,0 +1,66 @@
+/*!
+Serialize and deserialize `DateDiffResult` and `DateTimeDiffResult` as ISO 8601 duration strings instead of structs.
+
+```rust
+use date_differencer::DateTimeDiffResult;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Subscription {
+    #[serde(with = "date_differencer::serde_iso8601")]
+    period: DateTimeDiffResult,
+}
+
+let subscription = Subscription {
+    period: DateTimeDiffResult {
+        months: 1,
+        ..DateTimeDiffResult::default()
+    },
+};
+
+assert_eq!(r#"{"period":"P1M"}"#, serde_json::to_string(&subscription).unwrap());
+```
+*/
+
+use core::{
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::{
+    de::{self, Visitor},
+    Deserializer, Serializer,
+};
+
+use super::ParseDiffError;
+
+/// Serialize a `DateDiffResult` or a `DateTimeDiffResult` as an ISO 8601 duration string.
+#[inline]
+pub fn serialize<T: Display, S: Serializer>(value: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Deserialize a `DateDiffResult` or a `DateTimeDiffResult` from an ISO 8601 duration string.
+#[inline]
+pub fn deserialize<'de, T: FromStr<Err = ParseDiffError>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    struct Iso8601Visitor<T>(PhantomData<T>);
+
+    impl<'de, T: FromStr<Err = ParseDiffError>> Visitor<'de> for Iso8601Visitor<T> {
+        type Value = T;
+
+        #[inline]
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("an ISO 8601 duration string")
+        }
+
+        #[inline]
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(Iso8601Visitor(PhantomData))
+}
@@ -0,0 +1,250 @@
+/*!
+The `date_diff`, `date_time_diff` and `add_date_time_diff` functions for `time::Date`, `time::PrimitiveDateTime` and `time::OffsetDateTime`.
+
+```rust
+use date_differencer::{time_backend::*, DateTimeDiffResult};
+use time::{Date, Month, PrimitiveDateTime, Time};
+
+let a = PrimitiveDateTime::new(
+    Date::from_calendar_date(2022, Month::April, 6).unwrap(),
+    Time::MIDNIGHT,
+);
+let b = PrimitiveDateTime::new(
+    Date::from_calendar_date(2023, Month::June, 9).unwrap(),
+    Time::from_hms(1, 0, 0).unwrap(),
+);
+
+let diff = date_time_diff(a, b);
+
+assert_eq!(
+    DateTimeDiffResult {
+        years: 1,
+        months: 2,
+        days: 3,
+        hours: 1,
+        ..DateTimeDiffResult::default()
+    },
+    diff
+);
+
+assert_eq!(Some(b), add_date_time_diff(a, &diff));
+```
+*/
+
+use core::cmp::Ordering;
+
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+
+use super::{
+    add_diff::{_add_wall_clock, WallClock},
+    diff::_wall_clock_diff,
+    DateDiffResult, DateTimeDiff, DateTimeDiffResult, EndOfMonth,
+};
+
+mod private {
+    use time::{Date, Time};
+
+    pub trait Sealed: Sized {
+        fn to_date_time(&self) -> (Date, Time);
+
+        fn with_date_time(&self, date: Date, time: Time) -> Self;
+
+        #[inline]
+        fn offset_seconds(&self) -> i32 {
+            0
+        }
+    }
+}
+
+/// The types of the `time` crate which can be used with the functions in this module, i.e. `Date`, `PrimitiveDateTime` and `OffsetDateTime`.
+///
+/// A `Date` is at midnight. Two `OffsetDateTime` instances are calculated on the wall clock of the offset of `from`, and an `OffsetDateTime` keeps its offset when a difference is added to it. The offset never changes on the way, so this is the same as every `TimeSemantics` except `WallClock` for a chrono `DateTime<FixedOffset>`.
+pub trait Backend: private::Sealed + Copy + Ord {}
+
+impl private::Sealed for Date {
+    #[inline]
+    fn to_date_time(&self) -> (Date, Time) {
+        (*self, Time::MIDNIGHT)
+    }
+
+    #[inline]
+    fn with_date_time(&self, date: Date, _time: Time) -> Self {
+        date
+    }
+}
+
+impl Backend for Date {}
+
+impl private::Sealed for PrimitiveDateTime {
+    #[inline]
+    fn to_date_time(&self) -> (Date, Time) {
+        (self.date(), self.time())
+    }
+
+    #[inline]
+    fn with_date_time(&self, date: Date, time: Time) -> Self {
+        PrimitiveDateTime::new(date, time)
+    }
+}
+
+impl Backend for PrimitiveDateTime {}
+
+impl private::Sealed for OffsetDateTime {
+    #[inline]
+    fn to_date_time(&self) -> (Date, Time) {
+        (self.date(), self.time())
+    }
+
+    #[inline]
+    fn with_date_time(&self, date: Date, time: Time) -> Self {
+        PrimitiveDateTime::new(date, time).assume_offset(self.offset())
+    }
+
+    #[inline]
+    fn offset_seconds(&self) -> i32 {
+        self.offset().whole_seconds()
+    }
+}
+
+impl Backend for OffsetDateTime {}
+
+#[inline]
+fn to_wall_clock(date_time: &impl Backend) -> WallClock {
+    let (date, time) = date_time.to_date_time();
+
+    WallClock {
+        year:       date.year(),
+        month:      u8::from(date.month()) as i32 - 1,
+        date:       date.day() as i32,
+        hour:       time.hour() as i32,
+        minute:     time.minute() as i32,
+        second:     time.second() as i32,
+        nanosecond: time.nanosecond() as i32,
+    }
+}
+
+#[inline]
+fn from_wall_clock<T: Backend>(date_time: &T, wall_clock: &WallClock) -> Option<T> {
+    let month = Month::try_from(u8::try_from(wall_clock.month + 1).ok()?).ok()?;
+
+    let date = Date::from_calendar_date(wall_clock.year, month, wall_clock.date as u8).ok()?;
+    let time = Time::from_hms_nano(
+        wall_clock.hour as u8,
+        wall_clock.minute as u8,
+        wall_clock.second as u8,
+        wall_clock.nanosecond as u32,
+    )
+    .ok()?;
+
+    Some(date_time.with_date_time(date, time))
+}
+
+/// Calculate the difference between two `Date`, `PrimitiveDateTime` or `OffsetDateTime` instances.
+///
+/// # Example
+///
+/// ```rust
+/// use date_differencer::{time_backend::date_diff, DateDiffResult};
+/// use time::{Date, Month};
+///
+/// let date = Date::from_calendar_date(2020, Month::February, 27).unwrap();
+/// let date_after_1_year_2_days =
+///     Date::from_calendar_date(2021, Month::March, 1).unwrap();
+///
+/// assert_eq!(
+///     DateDiffResult {
+///         years: 1,
+///         days: 2,
+///         ..DateDiffResult::default()
+///     },
+///     date_diff(date, date_after_1_year_2_days)
+/// );
+/// ```
+#[inline]
+pub fn date_diff<T: Backend>(from: T, to: T) -> DateDiffResult {
+    date_time_diff(from, to).into()
+}
+
+/// Calculate the difference between two `Date`, `PrimitiveDateTime` or `OffsetDateTime` instances.
+///
+/// If two `OffsetDateTime` instances have different offsets, `to` is converted to the offset of `from` first, so `add_date_time_diff(from, &date_time_diff(from, to))` is the same instant as `to`.
+///
+/// # Example
+///
+/// ```rust
+/// use date_differencer::{time_backend::date_time_diff, DateTimeDiffResult};
+/// use time::{Date, Month, PrimitiveDateTime, Time, UtcOffset};
+///
+/// let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+///
+/// let date = PrimitiveDateTime::new(
+///     Date::from_calendar_date(2000, Month::January, 1).unwrap(),
+///     Time::MIDNIGHT,
+/// )
+/// .assume_offset(offset);
+/// let date_after_1_year_1_day_5_minutes = PrimitiveDateTime::new(
+///     Date::from_calendar_date(2001, Month::January, 2).unwrap(),
+///     Time::from_hms(0, 5, 0).unwrap(),
+/// )
+/// .assume_offset(offset);
+///
+/// assert_eq!(
+///     DateTimeDiffResult {
+///         years: 1,
+///         days: 1,
+///         minutes: 5,
+///         ..DateTimeDiffResult::default()
+///     },
+///     date_time_diff(date, date_after_1_year_1_day_5_minutes)
+/// );
+/// ```
+pub fn date_time_diff<T: Backend>(from: T, to: T) -> DateTimeDiffResult {
+    let from_wall_clock = to_wall_clock(&from);
+
+    // `to` is converted to the offset of `from`, so the two wall clocks are compared and counted on the same offset
+    let to_wall_clock = match _add_wall_clock(
+        to_wall_clock(&to),
+        &DateTimeDiffResult {
+            seconds: from.offset_seconds() - to.offset_seconds(),
+            ..DateTimeDiffResult::default()
+        },
+        EndOfMonth::Clamp,
+    ) {
+        Some(v) => v,
+        // the offsets are less than a day, so only the year `i32::MAX` can overflow, which the `time` crate does not reach
+        None => unreachable!(),
+    };
+
+    match to.cmp(&from) {
+        Ordering::Greater => _wall_clock_diff(&from_wall_clock, &to_wall_clock, false),
+        Ordering::Less => _wall_clock_diff(&to_wall_clock, &from_wall_clock, true),
+        Ordering::Equal => DateTimeDiffResult::default(),
+    }
+}
+
+/// Calculate `from` + `date_time_diff`. Return `None` if the result is out of the range of the `time` crate.
+///
+/// If the day of month does not exist after adding the years and the months, it is clamped to the last day of the month. The time components of `date_time_diff` are also added to a `Date`, and the time of day of the result is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use date_differencer::{time_backend::add_date_time_diff, DateDiffResult};
+/// use time::{Date, Month};
+///
+/// let date = Date::from_calendar_date(2021, Month::January, 31).unwrap();
+///
+/// assert_eq!(
+///     Some(Date::from_calendar_date(2021, Month::February, 28).unwrap()),
+///     add_date_time_diff(date, &DateDiffResult {
+///         months: 1,
+///         ..DateDiffResult::default()
+///     })
+/// );
+/// ```
+#[inline]
+pub fn add_date_time_diff<T: Backend>(from: T, date_time_diff: &dyn DateTimeDiff) -> Option<T> {
+    let wall_clock = _add_wall_clock(to_wall_clock(&from), date_time_diff, EndOfMonth::Clamp)?;
+
+    from_wall_clock(&from, &wall_clock)
+}
@@ -0,0 +1,211 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::{prelude::*, LocalResult};
+
+use super::{
+    add_date_time_diff_with_options, constants::*, date_time_diff_with_options,
+    diff::_time_nanoseconds_of_day, AddOptions, DateTimeDiff, DateTimeDiffResult, DiffOptions,
+};
+
+// the days from 0001-01-01 to 1970-01-01
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719163;
+
+// the `DateTime` of a Unix timestamp in nanoseconds in `timezone`, whose days are floored so that a timestamp before 1970 is on the day before
+fn timestamp_to_date_time<Tz: TimeZone>(timestamp: i64, timezone: &Tz) -> Option<DateTime<Tz>> {
+    let date = NaiveDate::from_num_days_from_ce_opt(
+        i32::try_from(timestamp.div_euclid(DAY_NANOSECONDS as i64) + UNIX_EPOCH_DAYS_FROM_CE)
+            .ok()?,
+    )?;
+
+    let nanoseconds_of_day = _time_nanoseconds_of_day(timestamp);
+
+    let time = NaiveTime::from_num_seconds_from_midnight_opt(
+        (nanoseconds_of_day / SECOND_NANOSECONDS) as u32,
+        (nanoseconds_of_day % SECOND_NANOSECONDS) as u32,
+    )?;
+
+    Some(timezone.from_utc_datetime(&date.and_time(time)))
+}
+
+#[inline]
+fn system_time_to_timestamp(system_time: SystemTime) -> Option<i64> {
+    let nanoseconds = match system_time.duration_since(UNIX_EPOCH) {
+        Ok(v) => v.as_nanos() as i128,
+        Err(e) => -(e.duration().as_nanos() as i128),
+    };
+
+    i64::try_from(nanoseconds).ok()
+}
+
+#[inline]
+fn timestamp_to_system_time(timestamp: i64) -> Option<SystemTime> {
+    let duration = Duration::from_nanos(timestamp.unsigned_abs());
+
+    if timestamp >= 0 {
+        UNIX_EPOCH.checked_add(duration)
+    } else {
+        UNIX_EPOCH.checked_sub(duration)
+    }
+}
+
+/// Calculate the difference between two Unix timestamps in nanoseconds in `timezone`, the same as `date_time_diff_with_options` of the two `DateTime` instances in `timezone` with the default options. Return `None` if a component cannot be represented by an `i32`.
+///
+/// A timestamp in seconds can be multiplied by `1_000_000_000` first. The timestamps before 1970 are negative.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::FixedOffset;
+/// use date_differencer::{unix_timestamp_diff, DateTimeDiffResult};
+///
+/// let timezone = FixedOffset::east_opt(8 * 3600).unwrap();
+///
+/// // 1969-12-31T16:00:00Z and 1970-02-01T00:00:00Z
+/// let from = -8 * 3600 * 1_000_000_000;
+/// let to = 31 * 86400 * 1_000_000_000;
+///
+/// assert_eq!(
+///     Some(DateTimeDiffResult {
+///         months: 1,
+///         hours: 8,
+///         ..DateTimeDiffResult::default()
+///     }),
+///     unix_timestamp_diff(from, to, &timezone)
+/// );
+/// ```
+pub fn unix_timestamp_diff<Tz: TimeZone>(
+    from: i64,
+    to: i64,
+    timezone: &Tz,
+) -> Option<DateTimeDiffResult> {
+    date_time_diff_with_options(
+        timestamp_to_date_time(from, timezone)?,
+        timestamp_to_date_time(to, timezone)?,
+        &DiffOptions::default(),
+    )
+}
+
+/// Calculate `timestamp` + `date_time_diff` in `timezone`, where `timestamp` is a Unix timestamp in nanoseconds, the same as `add_date_time_diff_with_options` of the `DateTime` in `timezone` with the default options. See `add_date_time_diff_with_options` for the result.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{LocalResult, Utc};
+/// use date_differencer::{add_unix_timestamp_diff, DateDiffResult};
+///
+/// // 2000-01-31T00:00:00Z
+/// let timestamp = 949_276_800 * 1_000_000_000;
+///
+/// assert_eq!(
+///     LocalResult::Single(951_782_400 * 1_000_000_000),
+///     add_unix_timestamp_diff(
+///         timestamp,
+///         &DateDiffResult {
+///             months: 1,
+///             ..DateDiffResult::default()
+///         },
+///         &Utc
+///     )
+/// );
+/// ```
+pub fn add_unix_timestamp_diff<Tz: TimeZone>(
+    timestamp: i64,
+    date_time_diff: &dyn DateTimeDiff,
+    timezone: &Tz,
+) -> LocalResult<i64> {
+    let from = match timestamp_to_date_time(timestamp, timezone) {
+        Some(v) => v,
+        None => return LocalResult::None,
+    };
+
+    match add_date_time_diff_with_options(from, date_time_diff, &AddOptions::default()) {
+        LocalResult::Single(v) => match v.timestamp_nanos_opt() {
+            Some(v) => LocalResult::Single(v),
+            None => LocalResult::None,
+        },
+        LocalResult::Ambiguous(a, b) => match (a.timestamp_nanos_opt(), b.timestamp_nanos_opt()) {
+            (Some(a), Some(b)) => LocalResult::Ambiguous(a, b),
+            _ => LocalResult::None,
+        },
+        LocalResult::None => LocalResult::None,
+    }
+}
+
+/// Calculate the difference between two `SystemTime` instances in `timezone`, the same as `unix_timestamp_diff`. Return `None` if a `SystemTime` is out of the range of a Unix timestamp in nanoseconds.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, SystemTime};
+///
+/// use chrono::Utc;
+/// use date_differencer::{system_time_diff, DateTimeDiffResult};
+///
+/// let from = SystemTime::now();
+/// let to = from + Duration::from_secs(90);
+///
+/// assert_eq!(
+///     Some(DateTimeDiffResult {
+///         minutes: 1,
+///         seconds: 30,
+///         ..DateTimeDiffResult::default()
+///     }),
+///     system_time_diff(from, to, &Utc)
+/// );
+/// ```
+#[inline]
+pub fn system_time_diff<Tz: TimeZone>(
+    from: SystemTime,
+    to: SystemTime,
+    timezone: &Tz,
+) -> Option<DateTimeDiffResult> {
+    unix_timestamp_diff(system_time_to_timestamp(from)?, system_time_to_timestamp(to)?, timezone)
+}
+
+/// Calculate `from` + `date_time_diff` in `timezone`, the same as `add_unix_timestamp_diff`. See `add_date_time_diff_with_options` for the result.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use chrono::Utc;
+/// use date_differencer::{add_system_time_diff, DateDiffResult};
+///
+/// assert_eq!(
+///     UNIX_EPOCH + Duration::from_secs(365 * 86400),
+///     add_system_time_diff(
+///         UNIX_EPOCH,
+///         &DateDiffResult {
+///             years: 1,
+///             ..DateDiffResult::default()
+///         },
+///         &Utc
+///     )
+///     .unwrap()
+/// );
+/// ```
+pub fn add_system_time_diff<Tz: TimeZone>(
+    from: SystemTime,
+    date_time_diff: &dyn DateTimeDiff,
+    timezone: &Tz,
+) -> LocalResult<SystemTime> {
+    let timestamp = match system_time_to_timestamp(from) {
+        Some(v) => v,
+        None => return LocalResult::None,
+    };
+
+    match add_unix_timestamp_diff(timestamp, date_time_diff, timezone) {
+        LocalResult::Single(v) => match timestamp_to_system_time(v) {
+            Some(v) => LocalResult::Single(v),
+            None => LocalResult::None,
+        },
+        LocalResult::Ambiguous(a, b) => {
+            match (timestamp_to_system_time(a), timestamp_to_system_time(b)) {
+                (Some(a), Some(b)) => LocalResult::Ambiguous(a, b),
+                _ => LocalResult::None,
+            }
+        },
+        LocalResult::None => LocalResult::None,
+    }
+}
@@ -0,0 +1,177 @@
+use super::{
+    constants::*,
+    date_time_weeks_diff_with_options,
+    diff::{_bracket, _component_mut, _to_naive_date_time, _wall_clock_nanoseconds},
+    AddOptions, DateTimeLike, DiffOptions, TimeSemantics, Unit,
+};
+
+/// An exact rational number. It is always reduced and its `denominator` is always positive.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Fraction {
+    numerator:   i128,
+    denominator: i128,
+}
+
+impl Fraction {
+    /// Create a reduced fraction with a positive denominator. Return `None` if `denominator` is zero or the reduced fraction cannot be represented by `i128`, e.g. `i128::MIN / -1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use date_differencer::Fraction;
+    ///
+    /// let fraction = Fraction::new(6, -4).unwrap();
+    ///
+    /// assert_eq!(-3, fraction.numerator());
+    /// assert_eq!(2, fraction.denominator());
+    /// assert_eq!(None, Fraction::new(1, 0));
+    /// ```
+    pub fn new(numerator: i128, denominator: i128) -> Option<Fraction> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let (mut a, mut b) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+
+        let negative = (numerator < 0) != (denominator < 0);
+
+        let numerator_abs = numerator.unsigned_abs() / a;
+        let denominator = i128::try_from(denominator.unsigned_abs() / a).ok()?;
+
+        let numerator = if negative {
+            // `2^127` as `i128` is `i128::MIN`, which is its own negation
+            (numerator_abs as i128).wrapping_neg()
+        } else {
+            i128::try_from(numerator_abs).ok()?
+        };
+
+        Some(Fraction {
+            numerator,
+            denominator,
+        })
+    }
+
+    /// The numerator, which has the sign of this fraction.
+    #[inline]
+    pub const fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    /// The denominator, which is always positive.
+    #[inline]
+    pub const fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// Convert this fraction to the nearest `f64`.
+    ///
+    /// The integer part and the remainder are converted separately so that large totals do not lose the fractional digits.
+    #[inline]
+    pub fn to_f64(&self) -> f64 {
+        let whole = self.numerator / self.denominator;
+        let remainder = self.numerator % self.denominator;
+
+        whole as f64 + remainder as f64 / self.denominator as f64
+    }
+}
+
+#[inline]
+fn unit_nanoseconds(unit: Unit) -> Option<u64> {
+    match unit {
+        Unit::Nanoseconds => Some(1),
+        Unit::Seconds => Some(SECOND_NANOSECONDS),
+        Unit::Minutes => Some(MINUTE_NANOSECONDS),
+        Unit::Hours => Some(HOUR_NANOSECONDS),
+        Unit::Days => Some(DAY_NANOSECONDS),
+        Unit::Weeks => Some(WEEK_NANOSECONDS),
+        Unit::Months | Unit::Years => None,
+    }
+}
+
+/// Calculate the total amount of time between two `DateTime` instances in the given unit as an exact fraction. Return `None` if the whole units cannot be represented by an `i32`.
+///
+/// Hours and smaller units are counted on the exact elapsed time, and days and weeks have fixed lengths on the wall clock. For months and years, the whole units are counted by the same decomposition as `date_time_diff`, and the remainder is interpolated against the length of the actual next month or year, as `Temporal.Duration.prototype.total` does.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{total_in_fraction, Fraction, Unit};
+///
+/// let from = Utc.with_ymd_and_hms(2021, 2, 1, 0, 0, 0).unwrap();
+/// let to = Utc.with_ymd_and_hms(2021, 3, 8, 0, 0, 0).unwrap();
+///
+/// // 1 month and 7 of the 31 days in March
+/// assert_eq!(
+///     Fraction::new(38, 31),
+///     total_in_fraction(from, to, Unit::Months)
+/// );
+/// ```
+pub fn total_in_fraction<DT: DateTimeLike>(from: DT, to: DT, unit: Unit) -> Option<Fraction> {
+    let from_date_time = _to_naive_date_time(&from)?;
+    let to_date_time = _to_naive_date_time(&to)?;
+
+    if let Some(unit_nanoseconds) = unit_nanoseconds(unit) {
+        let nanoseconds =
+            _wall_clock_nanoseconds(&to_date_time) - _wall_clock_nanoseconds(&from_date_time);
+
+        // hours and smaller units are the exact elapsed time, as `TimeSemantics::Hybrid` counts them
+        let nanoseconds = if unit < Unit::Days {
+            nanoseconds
+                - (to.offset_seconds() - from.offset_seconds()) as i128 * SECOND_NANOSECONDS as i128
+        } else {
+            nanoseconds
+        };
+
+        return Fraction::new(nanoseconds, unit_nanoseconds as i128);
+    }
+
+    let whole = date_time_weeks_diff_with_options(from, to, &DiffOptions {
+        largest_unit: unit,
+        smallest_unit: unit,
+        time_semantics: TimeSemantics::WallClock,
+        ..DiffOptions::default()
+    })?;
+
+    let (mut lower, lower_date_time, _, upper_date_time) =
+        _bracket(from_date_time, to_date_time, whole, unit, 1, &AddOptions::default())?;
+
+    let progress =
+        _wall_clock_nanoseconds(&to_date_time) - _wall_clock_nanoseconds(&lower_date_time);
+    let span = (_wall_clock_nanoseconds(&upper_date_time)
+        - _wall_clock_nanoseconds(&lower_date_time))
+    .abs();
+
+    Fraction::new(*_component_mut(&mut lower, unit) as i128 * span + progress, span)
+}
+
+/// Calculate the total amount of time between two `DateTime` instances in the given unit, including the fraction. Return `None` if the whole units cannot be represented by an `i32`.
+///
+/// See `total_in_fraction` for the exact value.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{total_in, Unit};
+///
+/// let from = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+/// let to = Utc.with_ymd_and_hms(2021, 7, 2, 12, 0, 0).unwrap();
+///
+/// // 1 year and 182.5 of the 365 days from 2021-01-01
+/// assert_eq!(Some(1.5), total_in(from, to, Unit::Years));
+///
+/// let from = Utc.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap();
+/// let to = Utc.with_ymd_and_hms(2021, 1, 16, 12, 0, 0).unwrap();
+///
+/// assert_eq!(Some(0.5), total_in(from, to, Unit::Months));
+/// assert_eq!(Some(15.5), total_in(from, to, Unit::Days));
+/// ```
+#[inline]
+pub fn total_in<DT: DateTimeLike>(from: DT, to: DT, unit: Unit) -> Option<f64> {
+    total_in_fraction(from, to, unit).map(|v| v.to_f64())
+}
@@ -0,0 +1,208 @@
+use chrono::{prelude::*, Duration};
+
+use super::{
+    add_diff::_disambiguate,
+    constants::*,
+    diff::{_date_time_nanoseconds_of_day, _nanoseconds_to_units_up_to},
+    DateTimeDiffResult, Disambiguation, Unit,
+};
+
+/// The opening hours of a day on the wall clock.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WorkingWindow {
+    /// The time of opening.
+    pub open:  NaiveTime,
+    /// The time of closing. If it is not after `open`, the window closes on the next day, e.g. from 22:00 to 06:00, and from 00:00 to 00:00 is the whole day.
+    pub close: NaiveTime,
+}
+
+impl WorkingWindow {
+    // the length of this window on the wall clock
+    #[inline]
+    fn nanoseconds(&self) -> u64 {
+        let open = _date_time_nanoseconds_of_day(&self.open);
+        let close = _date_time_nanoseconds_of_day(&self.close);
+
+        if close > open {
+            close - open
+        } else {
+            DAY_NANOSECONDS + close - open
+        }
+    }
+}
+
+/// The working hours of a week, such as from Monday to Friday, 09:00 to 18:00.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct WorkingHours {
+    /// The window of each weekday, indexed by `Weekday::num_days_from_monday`. `None` means closed all day. The time in an overnight window which overlaps the window of the next day is counted once.
+    pub windows: [Option<WorkingWindow>; 7],
+}
+
+impl WorkingHours {
+    /// Create working hours which have the same window on the weekdays from Monday to Friday, and are closed on the weekends.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chrono::NaiveTime;
+    /// use date_differencer::{WorkingHours, WorkingWindow};
+    ///
+    /// let hours = WorkingHours::weekdays(WorkingWindow {
+    ///     open:  NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+    ///     close: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+    /// });
+    ///
+    /// assert_eq!(None, hours.windows[5]);
+    /// ```
+    #[inline]
+    pub const fn weekdays(window: WorkingWindow) -> Self {
+        let w = Some(window);
+
+        WorkingHours {
+            windows: [w, w, w, w, w, None, None]
+        }
+    }
+}
+
+// the nanoseconds in the windows from `earlier` to `later`, where earlier <= later
+fn working_nanoseconds<Tz: TimeZone>(
+    earlier: &DateTime<Tz>,
+    later: &DateTime<Tz>,
+    hours: &WorkingHours,
+) -> i128 {
+    let timezone = earlier.timezone();
+
+    // a time which is skipped is moved forward by the gap, like the times which are not skipped
+    let resolve =
+        |local: &NaiveDateTime| _disambiguate(&timezone, local, Disambiguation::Compatible).ok();
+
+    let mut nanoseconds = 0;
+
+    // the end of the counted time, so that an overnight window which overlaps the next one is not counted twice
+    let mut counted = earlier.clone();
+
+    // a window which opens on the previous day can still be open
+    let first = earlier.naive_local().date();
+    let mut date = first.pred_opt().or(Some(first));
+    let last = later.naive_local().date();
+
+    while let Some(d) = date {
+        if d > last {
+            break;
+        }
+
+        if let Some(window) = hours.windows[d.weekday().num_days_from_monday() as usize] {
+            let open = d.and_time(window.open);
+            let close = open.checked_add_signed(Duration::nanoseconds(window.nanoseconds() as i64));
+
+            // the window is on the wall clock, so it is shorter or longer when the offset changes in it
+            if let (Some(open), Some(close)) = (resolve(&open), close.and_then(|v| resolve(&v))) {
+                let start = if open > counted { open } else { counted.clone() };
+                let end = if close < *later { close } else { later.clone() };
+
+                if end > start {
+                    if let Some(v) = end.clone().signed_duration_since(start).num_nanoseconds() {
+                        nanoseconds += v as i128;
+                    }
+
+                    counted = end;
+                }
+            }
+        }
+
+        date = d.succ_opt();
+    }
+
+    nanoseconds
+}
+
+#[inline]
+fn signed_working_nanoseconds<Tz: TimeZone>(
+    from: &DateTime<Tz>,
+    to: &DateTime<Tz>,
+    hours: &WorkingHours,
+) -> i128 {
+    if from <= to {
+        working_nanoseconds(from, to, hours)
+    } else {
+        -working_nanoseconds(to, from, hours)
+    }
+}
+
+/// Calculate the elapsed time from `from` to `to` which is inside the working windows. If `to` is earlier than `from`, the result is negative.
+///
+/// The windows are on the wall clock of the time zone of `from`, so a window which contains a DST transition is shorter or longer than it looks. A time which does not exist is moved forward by the gap, and an ambiguous time is the earlier one.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::{prelude::*, Duration};
+/// use date_differencer::{working_time_between, WorkingHours, WorkingWindow};
+///
+/// let hours = WorkingHours::weekdays(WorkingWindow {
+///     open:  NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+///     close: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+/// });
+///
+/// // from Friday 16:30 to Monday 10:00
+/// let from = Utc.with_ymd_and_hms(2024, 3, 8, 16, 30, 0).unwrap();
+/// let to = Utc.with_ymd_and_hms(2024, 3, 11, 10, 0, 0).unwrap();
+///
+/// assert_eq!(Duration::minutes(150), working_time_between(from, to, &hours));
+/// ```
+pub fn working_time_between<Tz: TimeZone>(
+    from: DateTime<Tz>,
+    to: DateTime<Tz>,
+    hours: &WorkingHours,
+) -> Duration {
+    let nanoseconds = signed_working_nanoseconds(&from, &to, hours);
+
+    Duration::seconds((nanoseconds / SECOND_NANOSECONDS as i128) as i64)
+        + Duration::nanoseconds((nanoseconds % SECOND_NANOSECONDS as i128) as i64)
+}
+
+/// Calculate the elapsed time from `from` to `to` which is inside the working windows, in hours plus minutes plus seconds plus nanoseconds. If `to` is earlier than `from`, the result is negative.
+///
+/// The `years`, `months` and `days` are always 0 because a working day has no fixed length. Return `None` if the hours overflow. See `working_time_between` for how the windows are placed.
+///
+/// # Example
+///
+/// ```rust
+/// use chrono::prelude::*;
+/// use date_differencer::{
+///     working_time_diff, DateTimeDiffResult, WorkingHours, WorkingWindow,
+/// };
+///
+/// let hours = WorkingHours::weekdays(WorkingWindow {
+///     open:  NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+///     close: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+/// });
+///
+/// let from = Utc.with_ymd_and_hms(2024, 3, 4, 8, 0, 0).unwrap();
+/// let to = Utc.with_ymd_and_hms(2024, 3, 5, 12, 15, 0).unwrap();
+///
+/// assert_eq!(
+///     Some(DateTimeDiffResult {
+///         hours: 12,
+///         minutes: 15,
+///         ..DateTimeDiffResult::default()
+///     }),
+///     working_time_diff(from, to, &hours)
+/// );
+/// ```
+pub fn working_time_diff<Tz: TimeZone>(
+    from: DateTime<Tz>,
+    to: DateTime<Tz>,
+    hours: &WorkingHours,
+) -> Option<DateTimeDiffResult> {
+    let result =
+        _nanoseconds_to_units_up_to(signed_working_nanoseconds(&from, &to, hours), Unit::Hours)?;
+
+    Some(DateTimeDiffResult {
+        hours: result.hours,
+        minutes: result.minutes,
+        seconds: result.seconds,
+        nanoseconds: result.nanoseconds,
+        ..DateTimeDiffResult::default()
+    })
+}
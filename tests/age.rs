@@ -0,0 +1,60 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use chrono::prelude::*;
+use common::*;
+use date_differencer::*;
+
+#[test]
+fn leap_day_birth() {
+    let birth = date(2000, 2, 29);
+
+    for (on, feb_28, mar_1) in [
+        (date(2001, 2, 27), (0, 11, 29), (0, 11, 29)),
+        (date(2001, 2, 28), (1, 0, 0), (0, 11, 30)),
+        (date(2001, 3, 1), (1, 0, 1), (1, 0, 0)),
+        (date(2004, 2, 28), (3, 11, 30), (3, 11, 30)),
+        (date(2004, 2, 29), (4, 0, 0), (4, 0, 0)),
+        (date(2005, 2, 28), (5, 0, 0), (4, 11, 30)),
+    ] {
+        for (leap_day_rule, (years, months, days)) in
+            [(LeapDayRule::Feb28, feb_28), (LeapDayRule::Mar1, mar_1)]
+        {
+            assert_eq!(
+                Some(Age {
+                    completed_years: years,
+                    diff:            DateDiffResult {
+                        years,
+                        months,
+                        days,
+                    },
+                }),
+                age(birth, on, leap_day_rule),
+                "{leap_day_rule:?} {on}"
+            );
+        }
+    }
+
+    assert_eq!(None, age(birth, date(2000, 2, 28), LeapDayRule::Feb28));
+    assert_eq!(Some(Age::default()), age(birth, birth, LeapDayRule::Feb28));
+}
+
+#[test]
+fn same_as_diff_otherwise() {
+    for _ in 0..10000 {
+        let a = random_naive_date();
+        let b = random_naive_date();
+
+        let (birth, on) = if a <= b { (a, b) } else { (b, a) };
+
+        let mar_1 = age(birth, on, LeapDayRule::Mar1).unwrap();
+
+        assert_eq!(date_only_diff(birth, on).years, mar_1.completed_years, "{birth} {on}");
+
+        if birth.month() != 2 || birth.day() != 29 {
+            assert_eq!(Some(mar_1.clone()), age(birth, on, LeapDayRule::Feb28), "{birth} {on}");
+            assert_eq!(date_only_diff(birth, on), mar_1.diff, "{birth} {on}");
+        }
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "chrono")]
+#![allow(clippy::needless_update)]
+
+mod common;
+
+use chrono::{prelude::*, LocalResult};
+use common::*;
+use date_differencer::*;
+
+#[test]
+fn leap_day_origin() {
+    let origin = Utc.with_ymd_and_hms(2000, 2, 29, 0, 0, 0).unwrap();
+    let date = Utc.with_ymd_and_hms(2001, 2, 28, 0, 0, 0).unwrap();
+
+    // the anniversary on 2001-02-28 is not after itself
+    assert_eq!(
+        Utc.with_ymd_and_hms(2002, 2, 28, 0, 0, 0).unwrap(),
+        next_anniversary(origin, date).unwrap().0
+    );
+    assert_eq!(origin, previous_anniversary(origin, date).unwrap().0);
+
+    let (anniversary, diff) = next_anniversary_with_options(origin, date, &AddOptions {
+        end_of_month: EndOfMonth::Overflow,
+        ..AddOptions::default()
+    })
+    .unwrap();
+
+    assert_eq!(Utc.with_ymd_and_hms(2001, 3, 1, 0, 0, 0).unwrap(), anniversary);
+    assert_eq!(
+        DateTimeDiffResult {
+            days: 1,
+            ..DateTimeDiffResult::default()
+        },
+        diff
+    );
+
+    assert_eq!(
+        Utc.with_ymd_and_hms(1996, 2, 29, 0, 0, 0).unwrap(),
+        previous_anniversary_with_options(origin, origin, &AddOptions {
+            end_of_month: EndOfMonth::Reject,
+            ..AddOptions::default()
+        })
+        .unwrap()
+        .0
+    );
+}
+
+#[test]
+fn fixed_offset() {
+    let timezone = FixedOffset::east_opt(9 * 3600).unwrap();
+
+    let origin = timezone.with_ymd_and_hms(2010, 4, 1, 23, 0, 0).unwrap();
+    let after = Utc.with_ymd_and_hms(2024, 4, 1, 12, 0, 0).unwrap().with_timezone(&timezone);
+
+    // 2024-04-01 21:00 on the wall clock
+    assert_eq!(
+        LocalResult::Single((
+            timezone.with_ymd_and_hms(2024, 4, 1, 23, 0, 0).unwrap(),
+            DateTimeDiffResult {
+                hours: 2,
+                ..DateTimeDiffResult::default()
+            }
+        )),
+        next_anniversary(origin, after)
+    );
+}
+
+#[test]
+fn between_anniversaries() {
+    for _ in 0..10000 {
+        let origin = random_date();
+        let date = random_date();
+
+        let (next, next_diff) = next_anniversary(origin, date).unwrap();
+        let (previous, previous_diff) = previous_anniversary(origin, date).unwrap();
+
+        assert!(previous < date && date < next, "{origin} {date}");
+        assert_eq!(
+            LocalResult::Single(next),
+            add_date_time_diff(date, &next_diff),
+            "{origin} {date}"
+        );
+        assert_eq!(
+            LocalResult::Single(date),
+            add_date_time_diff(previous, &previous_diff),
+            "{origin} {date}"
+        );
+
+        // no anniversary is between them except `date` itself
+        let (following, _) = next_anniversary(origin, previous).unwrap();
+
+        assert!(following == next || following == date, "{origin} {date}");
+    }
+}
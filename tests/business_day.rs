@@ -0,0 +1,166 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use chrono::{prelude::*, Duration, LocalResult};
+use common::*;
+use date_differencer::*;
+use rand::Rng;
+
+// counting the business days one by one
+fn business_days_between_naive(
+    from: NaiveDate,
+    to: NaiveDate,
+    calendar: &impl BusinessCalendar,
+) -> i32 {
+    let (earlier, later, sign) = if from <= to { (from, to, 1) } else { (to, from, -1) };
+
+    earlier
+        .iter_days()
+        .skip(1)
+        .take_while(|v| *v <= later)
+        .filter(|v| calendar.is_business_day(*v))
+        .count() as i32
+        * sign
+}
+
+#[test]
+fn holidays() {
+    let holidays = [
+        NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 12, 25).unwrap(),
+        NaiveDate::from_ymd_opt(2024, 12, 26).unwrap(),
+        // Saturday
+        NaiveDate::from_ymd_opt(2024, 12, 28).unwrap(),
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(),
+    ];
+
+    let calendar = HolidayCalendar {
+        holidays: &holidays,
+        ..HolidayCalendar::default()
+    };
+
+    let from = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+    let to = NaiveDate::from_ymd_opt(2025, 1, 3).unwrap();
+
+    // 27, 30, 31, 2, 3
+    assert_eq!(5, business_days_between(from, to, &calendar));
+    assert_eq!(0, business_days_between(from, from, &calendar));
+    assert!(!calendar.is_business_day(NaiveDate::from_ymd_opt(2024, 12, 25).unwrap()));
+
+    let from = Utc.with_ymd_and_hms(2024, 12, 24, 9, 30, 0).unwrap();
+
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2024, 12, 27, 9, 30, 0).unwrap()),
+        add_business_days(from, 1, &calendar)
+    );
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2025, 1, 2, 9, 30, 0).unwrap()),
+        add_business_days(from, 4, &calendar)
+    );
+    assert_eq!(LocalResult::Single(from), add_business_days(from, 0, &calendar));
+
+    // from a holiday
+    let from = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2024, 12, 27, 0, 0, 0).unwrap()),
+        add_business_days(from, -3, &calendar)
+    );
+}
+
+#[test]
+fn custom_calendar() {
+    // a Friday and Saturday weekend, with the 13th of every month as a holiday
+    struct Calendar;
+
+    impl BusinessCalendar for Calendar {
+        fn is_weekend(&self, weekday: Weekday) -> bool {
+            matches!(weekday, Weekday::Fri | Weekday::Sat)
+        }
+
+        fn count_holidays(&self, from: NaiveDate, to: NaiveDate) -> u32 {
+            from.iter_days()
+                .skip(1)
+                .take_while(|v| *v <= to)
+                .filter(|v| v.day() == 13 && !self.is_weekend(v.weekday()))
+                .count() as u32
+        }
+    }
+
+    // Thursday
+    let from = Utc.with_ymd_and_hms(2024, 6, 13, 0, 0, 0).unwrap();
+
+    // Sunday 16th
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2024, 6, 16, 0, 0, 0).unwrap()),
+        add_business_days(from, 1, &Calendar)
+    );
+    // Wednesday 12th
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2024, 6, 12, 0, 0, 0).unwrap()),
+        add_business_days(from, -1, &Calendar)
+    );
+
+    for _ in 0..1000 {
+        let from = random_date().date_naive();
+        let to = from + Duration::days(rand::rng().random_range(-100..=100));
+
+        assert_eq!(
+            business_days_between_naive(from, to, &Calendar),
+            business_days_between(from, to, &Calendar),
+            "{from} {to}"
+        );
+    }
+}
+
+#[test]
+fn no_business_days() {
+    let calendar = HolidayCalendar {
+        weekend: [true; 7],
+        ..HolidayCalendar::default()
+    };
+
+    let from = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+    assert_eq!(0, business_days_between(from, to, &calendar));
+    assert_eq!(LocalResult::None, add_business_days(from, 1, &calendar));
+    assert_eq!(LocalResult::Single(from), add_business_days(from, 0, &calendar));
+}
+
+#[test]
+fn add_then_count() {
+    let mut rng = rand::rng();
+
+    let mut holidays: Vec<NaiveDate> = (0..2000).map(|_| random_date().date_naive()).collect();
+
+    holidays.sort();
+
+    let calendar = HolidayCalendar {
+        weekend:  [false, false, false, false, true, true, false],
+        holidays: &holidays,
+    };
+
+    for _ in 0..10000 {
+        let from = random_date();
+        let n = rng.random_range(-1000..=1000);
+
+        let result = add_business_days(from, n, &calendar).unwrap();
+
+        assert_eq!(from.time(), result.time());
+        assert!(calendar.is_business_day(result.date_naive()) || n == 0, "{from} {n}");
+
+        if n >= 0 || calendar.is_business_day(from.date_naive()) {
+            assert_eq!(n, business_days_between(from, result, &calendar), "{from} {n}");
+        }
+
+        let to = from + Duration::days(rng.random_range(-500..=500));
+
+        assert_eq!(
+            business_days_between_naive(from.date_naive(), to.date_naive(), &calendar),
+            business_days_between(from, to, &calendar),
+            "{from} {to}"
+        );
+    }
+}
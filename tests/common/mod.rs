@@ -0,0 +1,104 @@
+#![allow(dead_code)]
+
+use chrono::{prelude::*, Duration, LocalResult};
+use date_differencer::DateTimeDiffResult;
+use rand::Rng;
+
+pub fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+pub fn random_date() -> DateTime<Utc> {
+    let mut rng = rand::rng();
+
+    let random_timestamp_millis = rng.random_range(-1000000000000..=3000000000000);
+
+    DateTime::from_timestamp_millis(random_timestamp_millis).unwrap()
+}
+
+pub fn random_local_date() -> DateTime<Local> {
+    random_date().with_timezone(&Local)
+}
+
+pub fn random_fixed_offset_date() -> DateTime<FixedOffset> {
+    let mut rng = rand::rng();
+
+    let random_offset = FixedOffset::east_opt(rng.random_range(-56..=56) * 15 * 60).unwrap();
+
+    random_date().with_timezone(&random_offset)
+}
+
+pub fn random_naive_date() -> NaiveDate {
+    random_date().date_naive()
+}
+
+pub fn random_diff() -> DateTimeDiffResult {
+    let mut rng = rand::rng();
+
+    DateTimeDiffResult {
+        years:       rng.random_range(-10..=10),
+        months:      rng.random_range(-30..=30),
+        days:        rng.random_range(-100..=100),
+        hours:       rng.random_range(-100..=100),
+        minutes:     rng.random_range(-100..=100),
+        seconds:     rng.random_range(-100..=100),
+        nanoseconds: rng.random_range(-1000000000..=1000000000),
+    }
+}
+
+// UTC+1, and UTC+2 from the last Sunday of March to the last Sunday of October at 01:00 UTC
+#[derive(Debug, Clone, Copy)]
+pub struct CentralEurope;
+
+impl CentralEurope {
+    fn last_sunday_at_one(year: i32, month: u32) -> NaiveDateTime {
+        let last_day = NaiveDate::from_ymd_opt(year, month + 1, 1).unwrap().pred_opt().unwrap();
+
+        let sunday = last_day - Duration::days(last_day.weekday().num_days_from_sunday() as i64);
+
+        sunday.and_hms_opt(1, 0, 0).unwrap()
+    }
+}
+
+impl TimeZone for CentralEurope {
+    type Offset = FixedOffset;
+
+    fn from_offset(_offset: &FixedOffset) -> Self {
+        CentralEurope
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+        self.offset_from_local_datetime(&local.and_hms_opt(12, 0, 0).unwrap())
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+        let offsets: Vec<FixedOffset> = [2, 1]
+            .into_iter()
+            .map(|h| FixedOffset::east_opt(h * 3600).unwrap())
+            .filter(|offset| {
+                self.offset_from_utc_datetime(
+                    &(*local - Duration::seconds(offset.local_minus_utc() as i64)),
+                ) == *offset
+            })
+            .collect();
+
+        match offsets[..] {
+            [offset] => LocalResult::Single(offset),
+            [a, b] => LocalResult::Ambiguous(a, b),
+            _ => LocalResult::None,
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        self.offset_from_utc_datetime(&utc.and_hms_opt(12, 0, 0).unwrap())
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        let year = utc.year();
+
+        let summer =
+            *utc >= Self::last_sunday_at_one(year, 3) && *utc < Self::last_sunday_at_one(year, 10);
+
+        FixedOffset::east_opt(if summer { 2 } else { 1 } * 3600).unwrap()
+    }
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use common::*;
+use date_differencer::*;
+
+#[test]
+fn thirty_360() {
+    let cases = [
+        // from, to, 30/360 US, 30E/360, 30E/360 ISDA
+        (date(2007, 1, 15), date(2007, 1, 30), 15, 15, 15),
+        (date(2007, 1, 31), date(2007, 2, 28), 28, 28, 30),
+        (date(2007, 2, 28), date(2007, 3, 31), 30, 32, 30),
+        (date(2006, 8, 31), date(2007, 2, 28), 178, 178, 180),
+        (date(2007, 2, 28), date(2007, 8, 31), 180, 182, 180),
+        (date(2008, 2, 29), date(2009, 2, 28), 360, 359, 360),
+        (date(2007, 1, 30), date(2007, 3, 31), 60, 60, 60),
+        (date(2007, 3, 31), date(2007, 4, 30), 30, 30, 30),
+    ];
+
+    for (from, to, us, e, e_isda) in cases {
+        assert_eq!(us, day_count(from, to, &DayCount::Thirty360Us), "{from} {to}");
+        assert_eq!(e, day_count(from, to, &DayCount::Thirty360E), "{from} {to}");
+        assert_eq!(
+            e_isda,
+            day_count(from, to, &DayCount::Thirty360EIsda {
+                maturity: None
+            }),
+            "{from} {to}"
+        );
+
+        assert_eq!(-us, day_count(to, from, &DayCount::Thirty360Us), "{from} {to}");
+    }
+
+    // the last day of February is kept on the maturity date
+    assert_eq!(
+        359,
+        day_count(date(2007, 2, 28), date(2008, 2, 29), &DayCount::Thirty360EIsda {
+            maturity: Some(date(2008, 2, 29)),
+        })
+    );
+
+    assert_eq!(
+        Fraction::new(1, 2).unwrap(),
+        year_fraction(date(2007, 2, 28), date(2007, 8, 31), &DayCount::Thirty360Us)
+    );
+}
+
+#[test]
+fn actual() {
+    let from = date(2003, 11, 1);
+    let to = date(2004, 5, 1);
+
+    assert_eq!(182, day_count(from, to, &DayCount::Act360));
+    assert_eq!(182, day_count(from, to, &DayCount::ActActIsda));
+
+    assert_eq!(Fraction::new(91, 180).unwrap(), year_fraction(from, to, &DayCount::Act360));
+    assert_eq!(Fraction::new(182, 365).unwrap(), year_fraction(from, to, &DayCount::Act365Fixed));
+    assert_eq!(
+        Fraction::new(-66491, 133590).unwrap(),
+        year_fraction(to, from, &DayCount::ActActIsda)
+    );
+
+    // whole calendar years are exactly that many years
+    assert_eq!(
+        Fraction::new(5, 1).unwrap(),
+        year_fraction(date(1999, 1, 1), date(2004, 1, 1), &DayCount::ActActIsda)
+    );
+
+    // a quarterly coupon period with 91 days
+    let icma = DayCount::ActActIcma {
+        period_start: date(1999, 11, 30),
+        period_end:   date(2000, 2, 29),
+        frequency:    4,
+    };
+
+    assert_eq!(
+        Fraction::new(1, 4).unwrap(),
+        year_fraction(date(1999, 11, 30), date(2000, 2, 29), &icma)
+    );
+    assert_eq!(
+        Fraction::new(31, 364).unwrap(),
+        year_fraction(date(1999, 11, 30), date(1999, 12, 31), &icma)
+    );
+}
+
+#[test]
+fn act_act_isda_adds_up() {
+    for _ in 0..10000 {
+        let a = random_naive_date();
+        let b = random_naive_date();
+        let c = random_naive_date();
+
+        let ab = year_fraction(a, b, &DayCount::ActActIsda);
+        let bc = year_fraction(b, c, &DayCount::ActActIsda);
+        let ac = year_fraction(a, c, &DayCount::ActActIsda);
+
+        assert_eq!(
+            ab.numerator() * bc.denominator() * ac.denominator()
+                + bc.numerator() * ab.denominator() * ac.denominator(),
+            ac.numerator() * ab.denominator() * bc.denominator(),
+            "{a} {b} {c}"
+        );
+
+        assert_eq!((b - a).num_days(), day_count(a, b, &DayCount::ActActIsda), "{a} {b}");
+    }
+}
@@ -1,14 +1,11 @@
-use chrono::{prelude::*, Duration, Months};
-use date_differencer::*;
-use rand::Rng;
-
-fn random_date() -> DateTime<Local> {
-    let mut rng = rand::rng();
+#![cfg(feature = "chrono")]
 
-    let random_timestamp_millis = rng.random_range(-1000000000000..=3000000000000);
+mod common;
 
-    DateTime::from_timestamp_millis(random_timestamp_millis).unwrap().with_timezone(&Local)
-}
+use chrono::{prelude::*, Duration, LocalResult, Months};
+use common::*;
+use date_differencer::*;
+use rand::Rng;
 
 #[test]
 fn basic_same_date() {
@@ -197,8 +194,8 @@ fn basic_diff_1_year_1_month_1_day_1_hour_1_minute_1_second_1_nanosecond() {
 #[test]
 fn add_diff_back() {
     for _ in 0..10000 {
-        let a = random_date();
-        let b = random_date();
+        let a = random_local_date();
+        let b = random_local_date();
 
         let diff = date_time_diff(a, b);
 
@@ -207,3 +204,192 @@ fn add_diff_back() {
             .contains(&b));
     }
 }
+
+#[test]
+fn date_only_same_as_midnight() {
+    for _ in 0..10000 {
+        let a = random_local_date().date_naive();
+        let b = random_local_date().date_naive();
+
+        assert_eq!(
+            date_diff(a.and_time(NaiveTime::MIN), b.and_time(NaiveTime::MIN)),
+            date_only_diff(a, b)
+        );
+    }
+}
+
+fn assert_sub_diff_back<Tz: TimeZone>(a: DateTime<Tz>, b: DateTime<Tz>)
+where
+    Tz::Offset: Copy + core::fmt::Display, {
+    let diff = date_time_diff(a, b);
+
+    let result = sub_date_time_diff(b, &diff);
+    let c = result.earliest().unwrap();
+
+    // up to 4 neighbouring dates can have the same diff to `b` because of the clamping, and the earliest one is returned
+    let candidates = (-3..=3)
+        .filter_map(|days| a.naive_local().checked_add_signed(Duration::days(days)))
+        .filter(|candidate| date_time_diff(*candidate, b.naive_local()) == diff)
+        .collect::<Vec<_>>();
+
+    assert!(candidates.contains(&a.naive_local()), "{a} {b}");
+    assert_eq!(candidates[0], c.naive_local(), "{a} {b}");
+
+    // `a` is given back if no other date has the same diff, and a wall clock in a fold is ambiguous
+    if candidates.len() == 1 {
+        match result {
+            LocalResult::Single(v) => assert_eq!(a, v, "{a} {b}"),
+            LocalResult::Ambiguous(x, y) => assert!(a == x || a == y, "{a} {b}"),
+            LocalResult::None => panic!("{a} {b}"),
+        }
+    }
+}
+
+#[test]
+fn sub_diff_back() {
+    for _ in 0..10000 {
+        let a = random_date();
+        let b = random_date();
+
+        assert_sub_diff_back(a, b);
+        assert_sub_diff_back(a.with_timezone(&CentralEurope), b.with_timezone(&CentralEurope));
+    }
+
+    // 02:30 is in the fold on 2024-10-27, and 2024-03-31 02:00 to 03:00 is the gap
+    for (a, b) in [
+        ((2024, 10, 26, 2, 30), (2024, 10, 27, 2, 30)),
+        ((2024, 10, 27, 2, 30), (2024, 10, 28, 1, 0)),
+        ((2024, 3, 30, 2, 30), (2024, 3, 31, 12, 0)),
+        ((2024, 3, 31, 12, 0), (2024, 3, 30, 2, 30)),
+        ((2024, 3, 31, 3, 30), (2024, 3, 30, 1, 30)),
+    ] {
+        let to_date_time = |(year, month, day, hour, minute)| {
+            CentralEurope.with_ymd_and_hms(year, month, day, hour, minute, 0).earliest().unwrap()
+        };
+
+        let (a, b) = (to_date_time(a), to_date_time(b));
+
+        assert_sub_diff_back(a, b);
+        assert_sub_diff_back(a + Duration::hours(1), b);
+        assert_sub_diff_back(b, a);
+    }
+}
+
+#[test]
+fn sub_diff_clamped() {
+    let a = Local.with_ymd_and_hms(2020, 1, 31, 0, 0, 0).unwrap();
+    let b = Local.with_ymd_and_hms(2020, 3, 1, 0, 0, 0).unwrap();
+
+    // 2020-01-29, 2020-01-30 and 2020-01-31 + 1 month 1 day are all 2020-03-01
+    assert_eq!(
+        Local.with_ymd_and_hms(2020, 1, 29, 0, 0, 0).unwrap(),
+        sub_date_time_diff(b, &date_time_diff(a, b)).unwrap()
+    );
+
+    let a = Local.with_ymd_and_hms(1960, 8, 30, 12, 0, 0).unwrap();
+    let b = Local.with_ymd_and_hms(1963, 3, 28, 23, 0, 0).unwrap();
+
+    // 1960-08-28 + 2 years 6 months 28 days is also 1963-03-28, but its diff to 1963-03-28 is 2 years 7 months
+    assert_eq!(
+        Local.with_ymd_and_hms(1960, 8, 29, 12, 0, 0).unwrap(),
+        sub_date_time_diff(b, &date_time_diff(a, b)).unwrap()
+    );
+}
+
+#[test]
+fn add_negative_multiples() {
+    let date = Local.with_ymd_and_hms(2021, 1, 2, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        Local.with_ymd_and_hms(2020, 1, 2, 0, 0, 0).unwrap(),
+        add_date_time_diff(date, &DateTimeDiffResult {
+            months: -12,
+            ..DateTimeDiffResult::default()
+        })
+        .unwrap()
+    );
+
+    assert_eq!(
+        Local.with_ymd_and_hms(2021, 1, 1, 0, 0, 0).unwrap(),
+        add_date_time_diff(date, &DateTimeDiffResult {
+            hours: -24,
+            ..DateTimeDiffResult::default()
+        })
+        .unwrap()
+    );
+
+    assert_eq!(
+        Local.with_ymd_and_hms(2021, 1, 1, 23, 0, 0).unwrap(),
+        add_date_time_diff(date, &DateTimeDiffResult {
+            minutes: -60,
+            ..DateTimeDiffResult::default()
+        })
+        .unwrap()
+    );
+
+    assert_eq!(
+        Local.with_ymd_and_hms(2021, 1, 1, 23, 59, 0).unwrap(),
+        add_date_time_diff(date, &DateTimeDiffResult {
+            seconds: -60,
+            ..DateTimeDiffResult::default()
+        })
+        .unwrap()
+    );
+
+    assert_eq!(
+        Local.with_ymd_and_hms(2021, 1, 1, 23, 59, 58).unwrap(),
+        add_date_time_diff(date, &DateTimeDiffResult {
+            nanoseconds: -2000000000,
+            ..DateTimeDiffResult::default()
+        })
+        .unwrap()
+    );
+}
+
+#[test]
+fn in_time_zone() {
+    let mut rng = rand::rng();
+
+    for _ in 0..10000 {
+        let offset = FixedOffset::east_opt(rng.random_range(-56..=56) * 15 * 60).unwrap();
+
+        let a = random_date();
+        let b = random_date().with_timezone(&offset);
+
+        for reference_tz in [offset, FixedOffset::east_opt(0).unwrap()] {
+            let diff = date_time_diff_in_time_zone(a, b, &reference_tz);
+
+            assert_eq!(
+                date_time_diff(a.with_timezone(&reference_tz), b.with_timezone(&reference_tz)),
+                diff,
+                "{a} {b} {reference_tz}"
+            );
+            assert_eq!(
+                b,
+                add_date_time_diff(a.with_timezone(&reference_tz), &diff).unwrap(),
+                "{a} {b} {reference_tz}"
+            );
+        }
+    }
+
+    let utc = Utc.with_ymd_and_hms(2024, 2, 29, 20, 0, 0).unwrap();
+    let local =
+        FixedOffset::west_opt(5 * 3600).unwrap().with_ymd_and_hms(2024, 3, 29, 16, 0, 0).unwrap();
+
+    // 2024-02-29T20:00:00Z is 2024-02-29T15:00:00-05:00, and 2024-03-29T16:00:00-05:00 is 2024-03-29T21:00:00Z
+    assert_eq!(
+        DateTimeDiffResult {
+            months: 1,
+            hours: 1,
+            ..DateTimeDiffResult::default()
+        },
+        date_time_diff_in_time_zone(utc, local, &local.timezone())
+    );
+    assert_eq!(
+        DateDiffResult {
+            months: 1,
+            ..DateDiffResult::default()
+        },
+        date_diff_in_time_zone(utc, local, &Utc)
+    );
+}
@@ -0,0 +1,182 @@
+#![cfg(feature = "chrono")]
+#![allow(clippy::needless_update)]
+
+mod common;
+
+use chrono::{prelude::*, LocalResult};
+use common::*;
+use date_differencer::*;
+
+const POLICIES: [EndOfMonth; 4] =
+    [EndOfMonth::Clamp, EndOfMonth::Overflow, EndOfMonth::Reject, EndOfMonth::StickToMonthEnd];
+
+fn add(from: DateTime<Utc>, months: i32, end_of_month: EndOfMonth) -> LocalResult<DateTime<Utc>> {
+    add_date_time_diff_with_options(
+        from,
+        &DateDiffResult {
+            months,
+            ..DateDiffResult::default()
+        },
+        &AddOptions {
+            end_of_month,
+            ..AddOptions::default()
+        },
+    )
+}
+
+fn diff(from: DateTime<Utc>, to: DateTime<Utc>, end_of_month: EndOfMonth) -> DateTimeDiffResult {
+    date_time_diff_with_options(from, to, &DiffOptions {
+        end_of_month,
+        ..DiffOptions::default()
+    })
+    .unwrap()
+}
+
+#[test]
+fn add_months() {
+    let jan_31 = Utc.with_ymd_and_hms(2020, 1, 31, 12, 0, 0).unwrap();
+
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2020, 2, 29, 12, 0, 0).unwrap()),
+        add(jan_31, 1, EndOfMonth::Clamp)
+    );
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2020, 3, 2, 12, 0, 0).unwrap()),
+        add(jan_31, 1, EndOfMonth::Overflow)
+    );
+    assert_eq!(LocalResult::None, add(jan_31, 1, EndOfMonth::Reject));
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2020, 3, 31, 12, 0, 0).unwrap()),
+        add(jan_31, 2, EndOfMonth::Reject)
+    );
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2019, 11, 30, 12, 0, 0).unwrap()),
+        add(jan_31, -2, EndOfMonth::StickToMonthEnd)
+    );
+
+    let feb_28 = Utc.with_ymd_and_hms(2021, 2, 28, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2021, 3, 28, 0, 0, 0).unwrap()),
+        add(feb_28, 1, EndOfMonth::Clamp)
+    );
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2021, 3, 31, 0, 0, 0).unwrap()),
+        add(feb_28, 1, EndOfMonth::StickToMonthEnd)
+    );
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2024, 2, 29, 0, 0, 0).unwrap()),
+        add(feb_28, 36, EndOfMonth::StickToMonthEnd)
+    );
+
+    // years are added as 12 months
+    assert_eq!(
+        LocalResult::Single(Utc.with_ymd_and_hms(2021, 3, 1, 0, 0, 0).unwrap()),
+        add_date_time_diff_with_options(
+            Utc.with_ymd_and_hms(2020, 2, 29, 0, 0, 0).unwrap(),
+            &DateDiffResult {
+                years: 1,
+                ..DateDiffResult::default()
+            },
+            &AddOptions {
+                end_of_month: EndOfMonth::Overflow,
+                ..AddOptions::default()
+            }
+        )
+    );
+}
+
+#[test]
+fn diff_months() {
+    let from = Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2021, 3, 2, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        DateTimeDiffResult {
+            months: 1,
+            days: 2,
+            ..DateTimeDiffResult::default()
+        },
+        diff(from, to, EndOfMonth::Clamp)
+    );
+
+    // 2021-01-31 + 1 month is 2021-03-03
+    assert_eq!(
+        DateTimeDiffResult {
+            days: 30,
+            ..DateTimeDiffResult::default()
+        },
+        diff(from, to, EndOfMonth::Overflow)
+    );
+
+    // 2021-05-31 - 3 months is 2021-03-03, which is after 2021-03-02
+    let from = Utc.with_ymd_and_hms(2021, 5, 31, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        DateTimeDiffResult {
+            months: -3,
+            days: -1,
+            ..DateTimeDiffResult::default()
+        },
+        diff(from, to, EndOfMonth::Overflow)
+    );
+
+    let from = Utc.with_ymd_and_hms(2021, 2, 28, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2021, 4, 30, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        DateTimeDiffResult {
+            months: 2,
+            days: 2,
+            ..DateTimeDiffResult::default()
+        },
+        diff(from, to, EndOfMonth::Clamp)
+    );
+    assert_eq!(
+        DateTimeDiffResult {
+            months: 2,
+            ..DateTimeDiffResult::default()
+        },
+        diff(from, to, EndOfMonth::StickToMonthEnd)
+    );
+    assert_eq!(
+        DateTimeDiffResult {
+            months: -2,
+            ..DateTimeDiffResult::default()
+        },
+        diff(to, from, EndOfMonth::StickToMonthEnd)
+    );
+
+    // 2020-10-31 + 1 month does not exist
+    let from = Utc.with_ymd_and_hms(2020, 10, 31, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2020, 12, 30, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        DateTimeDiffResult {
+            days: 60,
+            ..DateTimeDiffResult::default()
+        },
+        diff(from, to, EndOfMonth::Reject)
+    );
+}
+
+#[test]
+fn diff_add_back() {
+    for _ in 0..10000 {
+        let a = random_date();
+        let b = random_date();
+
+        for end_of_month in POLICIES {
+            let diff = diff(a, b, end_of_month);
+
+            assert_eq!(
+                LocalResult::Single(b),
+                add_date_time_diff_with_options(a, &diff, &AddOptions {
+                    end_of_month,
+                    ..AddOptions::default()
+                }),
+                "{end_of_month:?} {a} {b} {diff:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,236 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use common::*;
+use date_differencer::*;
+use rand::Rng;
+
+#[test]
+fn format() {
+    assert_eq!("PT0S", DateTimeDiffResult::default().to_string());
+    assert_eq!("P0D", DateDiffResult::default().to_string());
+
+    assert_eq!(
+        "P1Y2M3DT4H5M6.000000007S",
+        DateTimeDiffResult {
+            years:       1,
+            months:      2,
+            days:        3,
+            hours:       4,
+            minutes:     5,
+            seconds:     6,
+            nanoseconds: 7,
+        }
+        .to_string()
+    );
+
+    assert_eq!(
+        "-P1Y2M3DT4H5M6.5S",
+        DateTimeDiffResult {
+            years:       -1,
+            months:      -2,
+            days:        -3,
+            hours:       -4,
+            minutes:     -5,
+            seconds:     -6,
+            nanoseconds: -500000000,
+        }
+        .to_string()
+    );
+
+    assert_eq!(
+        "PT0.000001S",
+        DateTimeDiffResult {
+            nanoseconds: 1000,
+            ..DateTimeDiffResult::default()
+        }
+        .to_string()
+    );
+
+    assert_eq!(
+        "P1Y-2M",
+        DateDiffResult {
+            years: 1, months: -2, days: 0
+        }
+        .to_string()
+    );
+
+    assert_eq!(
+        "P1Y2M3D",
+        DateDiffResult {
+            years: 1, months: 2, days: 3
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn parse() {
+    assert_eq!(
+        Ok(DateTimeDiffResult {
+            years:       1,
+            months:      2,
+            days:        3,
+            hours:       4,
+            minutes:     5,
+            seconds:     6,
+            nanoseconds: 7,
+        }),
+        "P1Y2M3DT4H5M6.000000007S".parse()
+    );
+
+    assert_eq!(
+        Ok(DateTimeDiffResult {
+            days: -10,
+            seconds: -1,
+            nanoseconds: -500000000,
+            ..DateTimeDiffResult::default()
+        }),
+        "-P1W3DT1,5S".parse()
+    );
+
+    assert_eq!(
+        Ok(DateDiffResult {
+            years: 1, months: -2, days: 0
+        }),
+        "P1Y-2M".parse()
+    );
+
+    assert_eq!(Ok(DateTimeDiffResult::default()), "PT0S".parse());
+    assert_eq!(Ok(DateDiffResult::default()), "P0D".parse());
+
+    for s in ["", "P", "PT", "P1DT", "1Y", "P1", "P1H", "PT1D", "P1D2Y", "P1.5Y", "PT0.0000000001S"]
+    {
+        assert_eq!(Err(ParseDiffError::InvalidFormat), s.parse::<DateTimeDiffResult>(), "{s}");
+    }
+
+    assert_eq!(Err(ParseDiffError::OutOfRange), "P2147483648Y".parse::<DateTimeDiffResult>());
+    assert_eq!(Err(ParseDiffError::OutOfRange), "P400000000W".parse::<DateTimeDiffResult>());
+    assert_eq!(Ok(i32::MIN), "-P2147483648Y".parse::<DateTimeDiffResult>().map(|v| v.years));
+
+    assert_eq!(Err(ParseDiffError::UnexpectedTime), "P1DT1H".parse::<DateDiffResult>());
+}
+
+#[test]
+fn format_parse_back() {
+    for _ in 0..10000 {
+        let a = random_local_date();
+        let b = random_local_date();
+
+        let diff = date_time_diff(a, b);
+
+        assert_eq!(Ok(diff.clone()), diff.to_string().parse());
+
+        let diff = date_diff(a, b);
+
+        assert_eq!(Ok(diff.clone()), diff.to_string().parse());
+    }
+}
+
+#[test]
+fn format_parse_back_any_components() {
+    let mut rng = rand::rng();
+
+    for _ in 0..10000 {
+        let seconds = rng.random::<i32>();
+
+        // the nanoseconds are less than a second and have the sign of the seconds
+        let nanoseconds = match seconds {
+            0 => rng.random_range(-999999999..=999999999),
+            1.. => rng.random_range(0..=999999999),
+            _ => rng.random_range(-999999999..=0),
+        };
+
+        let diff = DateTimeDiffResult {
+            years: rng.random(),
+            months: rng.random(),
+            days: rng.random(),
+            hours: rng.random(),
+            minutes: rng.random(),
+            seconds,
+            nanoseconds,
+        };
+
+        assert_eq!(Ok(diff.clone()), diff.to_string().parse(), "{diff:?}");
+
+        // other nanoseconds are carried into the seconds, and are still parsed
+        let diff = DateTimeDiffResult {
+            nanoseconds: rng.random(),
+            ..diff
+        };
+
+        let parsed: DateTimeDiffResult = diff.to_string().parse().unwrap();
+
+        assert_eq!(
+            diff.seconds as i64 * 1000000000 + diff.nanoseconds as i64,
+            parsed.seconds as i64 * 1000000000 + parsed.nanoseconds as i64,
+            "{diff:?}"
+        );
+        assert_eq!(
+            DateTimeDiffResult {
+                seconds: 0,
+                nanoseconds: 0,
+                ..parsed
+            },
+            DateTimeDiffResult {
+                seconds: 0,
+                nanoseconds: 0,
+                ..diff
+            }
+        );
+    }
+}
+
+#[test]
+fn format_carried_nanoseconds() {
+    let diff = DateTimeDiffResult {
+        seconds: 1,
+        nanoseconds: -5,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!("PT0.999999995S", diff.to_string());
+    assert_eq!(
+        Ok(DateTimeDiffResult {
+            nanoseconds: 999999995,
+            ..DateTimeDiffResult::default()
+        }),
+        diff.to_string().parse()
+    );
+
+    let diff = DateTimeDiffResult {
+        days: 1,
+        nanoseconds: 2000000000,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!("P1DT2S", diff.to_string());
+
+    // the seconds past `i32` go back into the nanoseconds
+    for diff in [
+        DateTimeDiffResult {
+            seconds: i32::MAX,
+            nanoseconds: 1500000000,
+            ..DateTimeDiffResult::default()
+        },
+        DateTimeDiffResult {
+            seconds: i32::MIN,
+            nanoseconds: i32::MIN,
+            ..DateTimeDiffResult::default()
+        },
+    ] {
+        assert_eq!(Ok(diff.clone()), diff.to_string().parse(), "{diff}");
+    }
+
+    assert_eq!(
+        "PT2147483648.5S",
+        DateTimeDiffResult {
+            seconds: i32::MAX,
+            nanoseconds: 1500000000,
+            ..DateTimeDiffResult::default()
+        }
+        .to_string()
+    );
+    assert_eq!(Err(ParseDiffError::OutOfRange), "PT2147483650.2S".parse::<DateTimeDiffResult>());
+}
@@ -0,0 +1,274 @@
+#![cfg(all(feature = "chrono", feature = "jiff", feature = "std"))]
+
+mod common;
+
+use chrono::{prelude::*, Duration, LocalResult};
+use common::*;
+use date_differencer::{jiff_backend as backend, *};
+use jiff::{
+    civil,
+    tz::{self, TimeZone as JiffTimeZone},
+    Span, Timestamp, Unit as JiffUnit,
+};
+use rand::Rng;
+
+fn central_europe() -> JiffTimeZone {
+    JiffTimeZone::posix("CET-1CEST,M3.5.0/2,M10.5.0/3").unwrap()
+}
+
+fn to_timestamp(date_time: &DateTime<impl TimeZone>) -> Timestamp {
+    Timestamp::from_nanosecond(date_time.timestamp_nanos_opt().unwrap() as i128).unwrap()
+}
+
+fn to_civil(date_time: &DateTime<Utc>) -> civil::DateTime {
+    to_timestamp(date_time).to_zoned(JiffTimeZone::UTC).datetime()
+}
+
+#[test]
+fn same_as_chrono() {
+    for _ in 0..10000 {
+        let a = random_date();
+        let b = random_date();
+
+        let (ja, jb) = (to_civil(&a), to_civil(&b));
+
+        let diff = date_time_diff(a, b);
+
+        assert_eq!(diff, backend::date_time_diff(ja, jb), "{a} {b}");
+        assert_eq!(date_diff(a, b), backend::date_diff(ja, jb), "{a} {b}");
+        assert_eq!(
+            date_diff(
+                a.date_naive().and_time(NaiveTime::MIN),
+                b.date_naive().and_time(NaiveTime::MIN)
+            ),
+            backend::date_diff(ja.date(), jb.date()),
+            "{a} {b}"
+        );
+        assert_eq!(Some(jb), backend::add_date_time_diff(ja, &diff), "{a} {b}");
+
+        let random_diff = random_diff();
+
+        assert_eq!(
+            add_date_time_diff(a, &random_diff).single().map(|v| to_civil(&v)),
+            backend::add_date_time_diff(ja, &random_diff),
+            "{a} {random_diff:?}"
+        );
+    }
+}
+
+#[test]
+fn zoned() {
+    for _ in 0..10000 {
+        let a = random_date().with_timezone(&CentralEurope);
+        let b = random_date().with_timezone(&CentralEurope);
+
+        let ja = to_timestamp(&a).to_zoned(central_europe());
+        let jb = to_timestamp(&b).to_zoned(central_europe());
+
+        // the same wall clock
+        assert_eq!(to_civil(&a.naive_local().and_utc()), ja.datetime());
+
+        // a `Zoned` is counted with the default `TimeSemantics::Hybrid`
+        let diff = date_time_diff_with_options(a, b, &DiffOptions::default());
+
+        assert_eq!(diff, Some(backend::date_time_diff(ja.clone(), jb.clone())), "{a} {b}");
+
+        let random_diff = random_diff();
+
+        // a result out of the range is not compared
+        if let LocalResult::Single(v) =
+            add_date_time_diff_with_options(a, &random_diff, &AddOptions::default())
+        {
+            let result = backend::add_date_time_diff(ja.clone(), &random_diff).unwrap();
+
+            assert_eq!(to_timestamp(&v), result.timestamp(), "{a} {random_diff:?}");
+            assert_eq!(ja.time_zone(), result.time_zone());
+        }
+    }
+}
+
+#[test]
+fn zoned_fold() {
+    let mut rng = rand::rng();
+
+    // 2024-10-27 02:00 to 03:00 is on the wall clock twice
+    let fold = Utc.with_ymd_and_hms(2024, 10, 27, 0, 0, 0).unwrap();
+
+    for _ in 0..10000 {
+        let a = (fold + Duration::milliseconds(rng.random_range(0..2 * 3600000)))
+            .with_timezone(&CentralEurope);
+        let b = (fold + Duration::milliseconds(rng.random_range(-3 * 86400000..=3 * 86400000)))
+            .with_timezone(&CentralEurope);
+
+        let ja = to_timestamp(&a).to_zoned(central_europe());
+        let jb = to_timestamp(&b).to_zoned(central_europe());
+
+        for (a, b, ja, jb) in [(a, b, &ja, &jb), (b, a, &jb, &ja)] {
+            let diff = backend::date_time_diff(ja.clone(), jb.clone());
+
+            assert_eq!(
+                date_time_diff_with_options(a, b, &DiffOptions::default()),
+                Some(diff.clone()),
+                "{a} {b}"
+            );
+            assert_eq!(
+                jb.timestamp(),
+                backend::add_date_time_diff(ja.clone(), &diff).unwrap().timestamp(),
+                "{a} {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn different_time_zones() {
+    for _ in 0..10000 {
+        let a = random_fixed_offset_date();
+        let b = random_fixed_offset_date();
+
+        let ja = to_timestamp(&a).to_zoned(JiffTimeZone::fixed(
+            tz::Offset::from_seconds(a.offset().local_minus_utc()).unwrap(),
+        ));
+        let jb = to_timestamp(&b).to_zoned(central_europe());
+
+        let diff = backend::date_time_diff(ja.clone(), jb.clone());
+
+        // `to` is counted in the time zone of `from`
+        assert_eq!(
+            backend::date_time_diff(ja.clone(), jb.with_time_zone(ja.time_zone().clone())),
+            diff,
+            "{a} {b}"
+        );
+
+        let result = backend::add_date_time_diff(ja.clone(), &diff).unwrap();
+
+        assert_eq!(jb.timestamp(), result.timestamp(), "{a} {b}");
+        assert_eq!(ja.time_zone(), result.time_zone());
+    }
+
+    // 2024-07-01 09:00 +09:00 is 2024-07-01 02:00 CEST
+    let from = civil::date(2024, 7, 1)
+        .at(9, 0, 0, 0)
+        .to_zoned(JiffTimeZone::fixed(tz::offset(9)))
+        .unwrap();
+    let to = civil::date(2024, 7, 1).at(2, 0, 0, 0).to_zoned(central_europe()).unwrap();
+
+    assert_eq!(DateTimeDiffResult::default(), backend::date_time_diff(from, to));
+}
+
+#[test]
+fn zoned_gap() {
+    let from = civil::date(2024, 3, 30).at(2, 30, 0, 0).to_zoned(central_europe()).unwrap();
+
+    let one_day = DateDiffResult {
+        days: 1,
+        ..DateDiffResult::default()
+    };
+
+    // 02:30 does not exist on 2024-03-31, so it is moved forward by the gap
+    assert_eq!(
+        civil::date(2024, 3, 31).at(3, 30, 0, 0),
+        backend::add_date_time_diff(from, &one_day).unwrap().datetime()
+    );
+
+    let from = civil::date(2024, 10, 26).at(2, 30, 0, 0).to_zoned(central_europe()).unwrap();
+
+    // 02:30 is ambiguous on 2024-10-27, so the earlier one is used
+    assert_eq!(tz::offset(2), backend::add_date_time_diff(from, &one_day).unwrap().offset());
+
+    let from = civil::date(2024, 3, 30).at(12, 0, 0, 0).to_zoned(central_europe()).unwrap();
+    let to = civil::date(2024, 3, 31).at(11, 30, 0, 0).to_zoned(central_europe()).unwrap();
+
+    // the days are counted on the wall clock, and the rest is the exact elapsed time
+    assert_eq!(
+        DateTimeDiffResult {
+            hours: 22,
+            minutes: 30,
+            ..DateTimeDiffResult::default()
+        },
+        backend::date_time_diff(from.clone(), to)
+    );
+    assert_eq!(
+        civil::date(2024, 3, 31).at(13, 0, 0, 0),
+        backend::add_date_time_diff(from, &DateTimeDiffResult {
+            hours: 24,
+            ..DateTimeDiffResult::default()
+        })
+        .unwrap()
+        .datetime()
+    );
+}
+
+#[test]
+fn same_as_jiff() {
+    for _ in 0..10000 {
+        let (a, b) = (random_date(), random_date());
+        let (ja, jb) = (to_civil(&a), to_civil(&b));
+
+        let diff = backend::date_time_diff(ja, jb);
+
+        // jiff counts a negative span from `to` back to `from` in the same way
+        let span = ja.until((JiffUnit::Year, jb)).unwrap();
+
+        assert_eq!(diff, DateTimeDiffResult::try_from(span).unwrap(), "{a} {b}");
+        assert_eq!(
+            diff,
+            DateTimeDiffResult::try_from(Span::try_from(diff.clone()).unwrap()).unwrap()
+        );
+        assert_eq!(jb, ja + span, "{a} {b}");
+    }
+}
+
+#[test]
+fn span_conversions() {
+    let diff = DateTimeDiffResult {
+        years:       -1,
+        months:      -2,
+        days:        -3,
+        hours:       -4,
+        minutes:     -5,
+        seconds:     -6,
+        nanoseconds: -7,
+    };
+
+    let span = Span::try_from(diff.clone()).unwrap();
+
+    assert_eq!(-1, span.signum());
+    assert_eq!(diff, DateTimeDiffResult::try_from(span).unwrap());
+
+    assert_eq!(
+        Some(backend::SpanConversionError::MixedSigns),
+        Span::try_from(DateTimeDiffResult {
+            years: 1,
+            days: -1,
+            ..DateTimeDiffResult::default()
+        })
+        .err()
+    );
+    assert_eq!(
+        Some(backend::SpanConversionError::OutOfRange),
+        Span::try_from(DateTimeDiffResult {
+            years: 20000,
+            ..DateTimeDiffResult::default()
+        })
+        .err()
+    );
+
+    // weeks, milliseconds and microseconds are folded into the other units
+    let span = Span::new().weeks(2).days(1).milliseconds(1500).microseconds(2).nanoseconds(3);
+
+    assert_eq!(
+        Ok(DateTimeDiffResult {
+            days: 15,
+            seconds: 1,
+            nanoseconds: 500002003,
+            ..DateTimeDiffResult::default()
+        }),
+        DateTimeDiffResult::try_from(span)
+    );
+
+    assert_eq!(
+        Err(backend::SpanConversionError::OutOfRange),
+        DateTimeDiffResult::try_from(Span::new().seconds(3000000000i64))
+    );
+}
@@ -0,0 +1,92 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use chrono::LocalResult;
+use common::*;
+use date_differencer::*;
+
+#[test]
+fn normalize() {
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            years:       -2,
+            months:      -1,
+            days:        3,
+            hours:       -2,
+            minutes:     0,
+            seconds:     0,
+            nanoseconds: -1,
+        }),
+        DateTimeDiffResult {
+            years:       -1,
+            months:      -13,
+            days:        3,
+            hours:       -1,
+            minutes:     -60,
+            seconds:     1,
+            nanoseconds: -1000000001,
+        }
+        .normalize()
+    );
+
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            minutes: 30,
+            ..DateTimeDiffResult::default()
+        }),
+        DateTimeDiffResult {
+            hours: 1,
+            minutes: -30,
+            ..DateTimeDiffResult::default()
+        }
+        .normalize()
+    );
+
+    assert_eq!(
+        None,
+        DateTimeDiffResult {
+            hours: i32::MAX,
+            minutes: 60,
+            ..DateTimeDiffResult::default()
+        }
+        .normalize()
+    );
+
+    assert_eq!(
+        None,
+        DateDiffResult {
+            years: i32::MAX, months: 12, days: 0
+        }
+        .normalize()
+    );
+}
+
+#[test]
+fn mixed_sign() {
+    assert!(!DateTimeDiffResult::default().is_mixed_sign());
+    assert!(DateTimeDiffResult {
+        days: 1,
+        nanoseconds: -1,
+        ..DateTimeDiffResult::default()
+    }
+    .is_mixed_sign());
+    assert!(!DateDiffResult {
+        years: -1, months: 0, days: -1
+    }
+    .is_mixed_sign());
+}
+
+#[test]
+fn normalize_diff_results() {
+    for _ in 0..10000 {
+        let a = random_date();
+        let b = random_date();
+
+        let diff = date_time_diff(a, b);
+
+        assert!(!diff.is_mixed_sign());
+        assert_eq!(Some(diff.clone()), diff.normalize());
+        assert_eq!(LocalResult::Single(diff.clone()), diff.normalize_relative_to(a));
+    }
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "chrono")]
+
+use chrono::prelude::*;
+use date_differencer::*;
+
+#[test]
+fn component_wise() {
+    let a = DateTimeDiffResult {
+        years:       1,
+        months:      11,
+        days:        20,
+        hours:       23,
+        minutes:     30,
+        seconds:     59,
+        nanoseconds: 999999999,
+    };
+
+    let b = DateTimeDiffResult {
+        months: 1,
+        days: 15,
+        minutes: 30,
+        nanoseconds: 1,
+        ..DateTimeDiffResult::default()
+    };
+
+    let sum = a.clone() + b.clone();
+
+    assert_eq!(
+        DateTimeDiffResult {
+            years:       1,
+            months:      12,
+            days:        35,
+            hours:       23,
+            minutes:     60,
+            seconds:     59,
+            nanoseconds: 1000000000,
+        },
+        sum
+    );
+
+    assert_eq!(a, sum - b.clone());
+    assert_eq!(b.clone() + b.clone() + b.clone(), b.clone() * 3);
+    assert_eq!(b.clone().into_neg(), -b.clone());
+    assert_eq!(-(b.clone() * 2), b * -2);
+
+    let a = DateDiffResult {
+        years: 1, months: 6, days: 3
+    };
+
+    assert_eq!(
+        DateDiffResult {
+            years: 2, months: 12, days: 6
+        },
+        a.clone() * 2
+    );
+    assert_eq!(DateDiffResult::default(), a.clone() - a.clone());
+    assert_eq!(a.clone().into_neg(), -a);
+}
+
+#[test]
+fn overflow() {
+    let max = DateTimeDiffResult {
+        days: i32::MAX,
+        ..DateTimeDiffResult::default()
+    };
+
+    let one_day = DateTimeDiffResult {
+        days: 1,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!(None, max.checked_add(&one_day));
+    assert_eq!(None, max.checked_mul(2));
+    assert_eq!(None, max.checked_neg().unwrap().checked_sub(&one_day.checked_mul(2).unwrap()));
+
+    let min = DateDiffResult {
+        years: i32::MIN,
+        ..DateDiffResult::default()
+    };
+
+    assert_eq!(None, min.checked_neg());
+    assert_eq!(None, min.checked_mul(-1));
+    assert!(std::panic::catch_unwind(|| -min.clone()).is_err());
+}
+
+#[test]
+fn add_sum_of_diffs() {
+    let date = Local.with_ymd_and_hms(2020, 1, 31, 0, 0, 0).unwrap();
+
+    let extension = DateDiffResult {
+        months: 1,
+        ..DateDiffResult::default()
+    };
+
+    // 2020-01-31 + 3 months -> 2020-04-30, which differs from adding 1 month three times
+    assert_eq!(
+        Local.with_ymd_and_hms(2020, 4, 30, 0, 0, 0).unwrap(),
+        add_date_time_diff(date, &(extension * 3)).unwrap()
+    );
+}
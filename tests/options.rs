@@ -0,0 +1,125 @@
+#![cfg(feature = "chrono")]
+#![allow(clippy::needless_update)]
+
+mod common;
+
+use chrono::prelude::*;
+use common::*;
+use date_differencer::*;
+
+#[test]
+fn largest_unit_months() {
+    for _ in 0..10000 {
+        let a = random_local_date();
+        let b = random_local_date();
+
+        let diff = date_time_diff(a, b);
+
+        assert_eq!(
+            Some(DateTimeDiffResult {
+                years: 0,
+                months: diff.years * 12 + diff.months,
+                ..diff
+            }),
+            date_time_diff_with_options(a, b, &DiffOptions {
+                largest_unit: Unit::Months,
+                smallest_unit: Unit::Nanoseconds,
+                ..DiffOptions::default()
+            })
+        );
+    }
+}
+
+#[test]
+fn largest_unit_days() {
+    for _ in 0..10000 {
+        let a = random_local_date();
+        let b = random_local_date();
+
+        let diff = date_time_diff_with_options(a, b, &DiffOptions {
+            largest_unit: Unit::Days,
+            smallest_unit: Unit::Nanoseconds,
+            ..DiffOptions::default()
+        })
+        .unwrap();
+
+        assert_eq!(0, diff.years);
+        assert_eq!(0, diff.months);
+        assert!(diff.hours.abs() < 24);
+
+        let duration = b.naive_local() - a.naive_local();
+
+        assert_eq!(
+            duration.num_nanoseconds().unwrap(),
+            diff.days as i64 * 86400000000000
+                + diff.hours as i64 * 3600000000000
+                + diff.minutes as i64 * 60000000000
+                + diff.seconds as i64 * 1000000000
+                + diff.nanoseconds as i64
+        );
+    }
+}
+
+#[test]
+fn smallest_unit() {
+    for _ in 0..10000 {
+        let a = random_local_date();
+        let b = random_local_date();
+
+        let diff = date_time_diff(a, b);
+
+        assert_eq!(
+            Some(DateTimeDiffResult {
+                years: diff.years,
+                months: diff.months,
+                days: diff.days,
+                ..DateTimeDiffResult::default()
+            }),
+            date_time_diff_with_options(a, b, &DiffOptions {
+                largest_unit: Unit::Years,
+                smallest_unit: Unit::Days,
+                ..DiffOptions::default()
+            })
+        );
+
+        // the largest unit is raised to the smallest unit
+        assert_eq!(
+            Some(DateTimeDiffResult {
+                months: diff.years * 12 + diff.months,
+                ..DateTimeDiffResult::default()
+            }),
+            date_time_diff_with_options(a, b, &DiffOptions {
+                largest_unit: Unit::Hours,
+                smallest_unit: Unit::Months,
+                ..DiffOptions::default()
+            })
+        );
+    }
+}
+
+#[test]
+fn overflow() {
+    let a = Local.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+    let b = Local.with_ymd_and_hms(2000, 1, 1, 0, 0, 3).unwrap();
+
+    assert_eq!(
+        None,
+        date_time_diff_with_options(a, b, &DiffOptions {
+            largest_unit: Unit::Nanoseconds,
+            smallest_unit: Unit::Nanoseconds,
+            ..DiffOptions::default()
+        })
+    );
+
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            seconds: -3,
+            ..DateTimeDiffResult::default()
+        }),
+        date_time_diff_with_options(b, a, &DiffOptions {
+            largest_unit: Unit::Seconds,
+            smallest_unit: Unit::Nanoseconds,
+            ..DiffOptions::default()
+        })
+    );
+}
@@ -0,0 +1,128 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use chrono::{prelude::*, LocalResult};
+use common::*;
+use date_differencer::*;
+use rand::Rng;
+
+#[test]
+fn no_drift() {
+    let start = Utc.with_ymd_and_hms(2023, 1, 31, 8, 0, 0).unwrap();
+
+    let step = DateDiffResult {
+        months: 1,
+        ..DateDiffResult::default()
+    };
+
+    let occurrences: Vec<_> = recurrence_with_options(start, &step, RecurrenceOptions {
+        count: Some(13),
+        ..RecurrenceOptions::default()
+    })
+    .map(|v| v.unwrap().day())
+    .collect();
+
+    assert_eq!(vec![31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31, 31], occurrences);
+}
+
+#[test]
+fn limits() {
+    let start = Utc.with_ymd_and_hms(2024, 3, 10, 0, 0, 0).unwrap();
+
+    let step = DateTimeDiffResult {
+        hours: -36,
+        ..DateTimeDiffResult::default()
+    };
+
+    // `until` is a lower bound for a negative step
+    let occurrences: Vec<_> = recurrence_with_options(start, &step, RecurrenceOptions {
+        until: Some(Utc.with_ymd_and_hms(2024, 3, 7, 0, 0, 0).unwrap()),
+        ..RecurrenceOptions::default()
+    })
+    .map(|v| v.unwrap())
+    .collect();
+
+    assert_eq!(
+        vec![
+            start,
+            Utc.with_ymd_and_hms(2024, 3, 8, 12, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 3, 7, 0, 0, 0).unwrap(),
+        ],
+        occurrences
+    );
+
+    // the smaller limit wins
+    assert_eq!(
+        2,
+        recurrence_with_options(start, &step, RecurrenceOptions {
+            until: Some(Utc.with_ymd_and_hms(2024, 3, 7, 0, 0, 0).unwrap()),
+            count: Some(2),
+            ..RecurrenceOptions::default()
+        })
+        .count()
+    );
+
+    // the iteration ends when the step overflows
+    assert_eq!(
+        3,
+        recurrence(start, &DateTimeDiffResult {
+            nanoseconds: i32::MAX / 2,
+            ..DateTimeDiffResult::default()
+        })
+        .count()
+    );
+}
+
+#[test]
+fn nonexistent_occurrences() {
+    let start = Utc.with_ymd_and_hms(2023, 1, 31, 0, 0, 0).unwrap();
+
+    let occurrences: Vec<_> = recurrence_with_options(
+        start,
+        &DateDiffResult {
+            months: 1,
+            ..DateDiffResult::default()
+        },
+        RecurrenceOptions {
+            count: Some(4),
+            end_of_month: EndOfMonth::Reject,
+            ..RecurrenceOptions::default()
+        },
+    )
+    .collect();
+
+    assert_eq!(
+        vec![
+            LocalResult::Single(start),
+            LocalResult::None,
+            LocalResult::Single(Utc.with_ymd_and_hms(2023, 3, 31, 0, 0, 0).unwrap()),
+            LocalResult::None,
+        ],
+        occurrences
+    );
+}
+
+#[test]
+fn from_anchor() {
+    let mut rng = rand::rng();
+
+    for _ in 0..1000 {
+        let start = random_date();
+
+        let step = DateTimeDiffResult {
+            months: rng.random_range(-3..=3),
+            days: rng.random_range(-40..=40),
+            hours: rng.random_range(-30..=30),
+            ..DateTimeDiffResult::default()
+        };
+
+        for (n, occurrence) in recurrence(start, &step).take(20).enumerate() {
+            assert_eq!(
+                add_date_time_diff(start, &(step.clone() * n as i32)),
+                occurrence,
+                "{start} {step:?} {n}"
+            );
+        }
+    }
+}
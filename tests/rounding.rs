@@ -0,0 +1,169 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use chrono::prelude::*;
+use common::*;
+use date_differencer::*;
+
+fn round(
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    smallest_unit: Unit,
+    rounding_mode: RoundingMode,
+    rounding_increment: u32,
+) -> DateTimeDiffResult {
+    date_time_diff_with_options(from, to, &DiffOptions {
+        smallest_unit,
+        rounding_mode,
+        rounding_increment,
+        ..DiffOptions::default()
+    })
+    .unwrap()
+}
+
+#[test]
+fn carry_by_calendar() {
+    let from = Utc.with_ymd_and_hms(2020, 1, 31, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2021, 1, 20, 0, 0, 0).unwrap();
+
+    // 2020-01-31 + 11 months -> 2020-12-31, + 20 days -> 2021-01-20
+    assert_eq!(
+        DateTimeDiffResult {
+            months: 11,
+            days: 20,
+            ..DateTimeDiffResult::default()
+        },
+        date_time_diff(from, to)
+    );
+
+    // 20 days out of the 31 days from 2020-12-31 to 2021-01-31
+    assert_eq!(
+        DateTimeDiffResult {
+            years: 1,
+            ..DateTimeDiffResult::default()
+        },
+        round(from, to, Unit::Months, RoundingMode::HalfExpand, 1)
+    );
+
+    assert_eq!(
+        DateTimeDiffResult {
+            months: 11,
+            ..DateTimeDiffResult::default()
+        },
+        round(from, to, Unit::Months, RoundingMode::Floor, 1)
+    );
+
+    assert_eq!(
+        DateTimeDiffResult {
+            years: 1,
+            ..DateTimeDiffResult::default()
+        },
+        round(from, to, Unit::Years, RoundingMode::HalfEven, 1)
+    );
+
+    assert_eq!(
+        DateTimeDiffResult {
+            years: -1,
+            ..DateTimeDiffResult::default()
+        },
+        round(to, from, Unit::Months, RoundingMode::HalfExpand, 1)
+    );
+}
+
+#[test]
+fn increment() {
+    let from = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+
+    let to = Utc.with_ymd_and_hms(2020, 1, 1, 1, 7, 29).unwrap();
+
+    assert_eq!(
+        DateTimeDiffResult {
+            hours: 1,
+            ..DateTimeDiffResult::default()
+        },
+        round(from, to, Unit::Minutes, RoundingMode::HalfExpand, 15)
+    );
+
+    assert_eq!(
+        DateTimeDiffResult {
+            hours: 1,
+            minutes: 15,
+            ..DateTimeDiffResult::default()
+        },
+        round(from, to, Unit::Minutes, RoundingMode::Ceil, 15)
+    );
+
+    let to = Utc.with_ymd_and_hms(2020, 1, 1, 23, 53, 0).unwrap();
+
+    assert_eq!(
+        DateTimeDiffResult {
+            days: 1,
+            ..DateTimeDiffResult::default()
+        },
+        round(from, to, Unit::Minutes, RoundingMode::HalfExpand, 15)
+    );
+}
+
+#[test]
+fn signs_and_ties() {
+    let from = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2020, 1, 1, 0, 30, 0).unwrap();
+
+    assert_eq!(
+        DateTimeDiffResult::default(),
+        round(from, to, Unit::Hours, RoundingMode::HalfEven, 1)
+    );
+
+    assert_eq!(
+        DateTimeDiffResult {
+            hours: 1,
+            ..DateTimeDiffResult::default()
+        },
+        round(from, to, Unit::Hours, RoundingMode::HalfExpand, 1)
+    );
+
+    assert_eq!(
+        DateTimeDiffResult {
+            hours: -1,
+            ..DateTimeDiffResult::default()
+        },
+        round(to, from, Unit::Hours, RoundingMode::HalfExpand, 1)
+    );
+
+    assert_eq!(
+        DateTimeDiffResult {
+            hours: -1,
+            ..DateTimeDiffResult::default()
+        },
+        round(to, from, Unit::Hours, RoundingMode::Floor, 1)
+    );
+
+    assert_eq!(DateTimeDiffResult::default(), round(to, from, Unit::Hours, RoundingMode::Ceil, 1));
+
+    let to = Utc.with_ymd_and_hms(2020, 1, 1, 1, 30, 0).unwrap();
+
+    assert_eq!(
+        DateTimeDiffResult {
+            hours: 2,
+            ..DateTimeDiffResult::default()
+        },
+        round(from, to, Unit::Hours, RoundingMode::HalfEven, 1)
+    );
+}
+
+#[test]
+fn floor_and_ceil_bound_the_date() {
+    for _ in 0..10000 {
+        let a = random_date();
+        let b = random_date();
+
+        for unit in [Unit::Years, Unit::Months, Unit::Weeks, Unit::Days, Unit::Hours] {
+            let floor = round(a, b, unit, RoundingMode::Floor, 1);
+            let ceil = round(a, b, unit, RoundingMode::Ceil, 1);
+
+            assert!(add_date_time_diff(a, &floor).unwrap() <= b, "{a} {b} {unit:?} {floor:?}");
+            assert!(add_date_time_diff(a, &ceil).unwrap() >= b, "{a} {b} {unit:?} {ceil:?}");
+        }
+    }
+}
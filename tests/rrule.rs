@@ -0,0 +1,353 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use chrono::prelude::*;
+use common::*;
+use date_differencer::*;
+
+// the examples of RFC 5545 section 3.8.5.3, with UTC instead of America/New_York because the time of day is kept on the wall clock
+fn expand(dtstart: (i32, u32, u32), rule: &str, limit: usize) -> Vec<(i32, u32, u32)> {
+    let dtstart = Utc.with_ymd_and_hms(dtstart.0, dtstart.1, dtstart.2, 9, 0, 0).unwrap();
+
+    rule.parse::<RRule>()
+        .unwrap()
+        .occurrences(dtstart)
+        .take(limit)
+        .map(|v| {
+            assert_eq!(9, v.hour());
+
+            (v.year(), v.month(), v.day())
+        })
+        .collect()
+}
+
+fn dates(year: i32, month: u32, days: &[u32]) -> Vec<(i32, u32, u32)> {
+    days.iter().map(|day| (year, month, *day)).collect()
+}
+
+#[test]
+fn daily() {
+    assert_eq!(
+        dates(1997, 9, &[2, 3, 4, 5, 6, 7, 8, 9, 10, 11]),
+        expand((1997, 9, 2), "FREQ=DAILY;COUNT=10", 100)
+    );
+
+    let until = expand((1997, 9, 2), "RRULE:FREQ=DAILY;UNTIL=19971224T000000Z", 1000);
+
+    assert_eq!(113, until.len());
+    assert_eq!(Some(&(1997, 12, 23)), until.last());
+
+    assert_eq!(
+        [dates(1997, 9, &[2, 12, 22]), dates(1997, 10, &[2, 12])].concat(),
+        expand((1997, 9, 2), "FREQ=DAILY;INTERVAL=10;COUNT=5", 100)
+    );
+
+    // every day in January, for 3 years
+    let yearly = expand(
+        (1998, 1, 1),
+        "FREQ=YEARLY;UNTIL=20000131T140000Z;BYMONTH=1;BYDAY=SU,MO,TU,WE,TH,FR,SA",
+        1000,
+    );
+    let daily = expand((1998, 1, 1), "FREQ=DAILY;UNTIL=20000131T140000Z;BYMONTH=1", 1000);
+
+    assert_eq!(93, yearly.len());
+    assert_eq!(yearly, daily);
+}
+
+#[test]
+fn weekly() {
+    assert_eq!(
+        [
+            dates(1997, 9, &[2, 9, 16, 23, 30]),
+            dates(1997, 10, &[7, 14, 21, 28]),
+            dates(1997, 11, &[4])
+        ]
+        .concat(),
+        expand((1997, 9, 2), "FREQ=WEEKLY;COUNT=10", 100)
+    );
+
+    assert_eq!(
+        [dates(1997, 9, &[2, 4, 9, 11, 16, 18, 23, 25, 30]), dates(1997, 10, &[2])].concat(),
+        expand((1997, 9, 2), "FREQ=WEEKLY;UNTIL=19971007T000000Z;WKST=SU;BYDAY=TU,TH", 100)
+    );
+
+    assert_eq!(
+        [
+            dates(1997, 9, &[1, 3, 5, 15, 17, 19, 29]),
+            dates(1997, 10, &[1, 3, 13, 15, 17, 27, 29, 31]),
+            dates(1997, 11, &[10, 12, 14, 24, 26, 28]),
+            dates(1997, 12, &[8, 10, 12, 22]),
+        ]
+        .concat(),
+        expand(
+            (1997, 9, 1),
+            "FREQ=WEEKLY;INTERVAL=2;UNTIL=19971224T000000Z;WKST=SU;BYDAY=MO,WE,FR",
+            100
+        )
+    );
+
+    // the week start matters with an interval
+    assert_eq!(
+        [dates(1997, 8, &[5, 10, 19, 24])].concat(),
+        expand((1997, 8, 5), "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=MO", 100)
+    );
+    assert_eq!(
+        [dates(1997, 8, &[5, 17, 19, 31])].concat(),
+        expand((1997, 8, 5), "FREQ=WEEKLY;INTERVAL=2;COUNT=4;BYDAY=TU,SU;WKST=SU", 100)
+    );
+}
+
+#[test]
+fn monthly() {
+    assert_eq!(
+        vec![
+            (1997, 9, 5),
+            (1997, 10, 3),
+            (1997, 11, 7),
+            (1997, 12, 5),
+            (1998, 1, 2),
+            (1998, 2, 6),
+            (1998, 3, 6),
+            (1998, 4, 3),
+            (1998, 5, 1),
+            (1998, 6, 5),
+        ],
+        expand((1997, 9, 5), "FREQ=MONTHLY;COUNT=10;BYDAY=1FR", 100)
+    );
+
+    assert_eq!(
+        vec![
+            (1997, 9, 7),
+            (1997, 9, 28),
+            (1997, 11, 2),
+            (1997, 11, 30),
+            (1998, 1, 4),
+            (1998, 1, 25),
+            (1998, 3, 1),
+            (1998, 3, 29),
+            (1998, 5, 3),
+            (1998, 5, 31),
+        ],
+        expand((1997, 9, 7), "FREQ=MONTHLY;INTERVAL=2;COUNT=10;BYDAY=1SU,-1SU", 100)
+    );
+
+    assert_eq!(
+        vec![
+            (1997, 9, 22),
+            (1997, 10, 20),
+            (1997, 11, 17),
+            (1997, 12, 22),
+            (1998, 1, 19),
+            (1998, 2, 16)
+        ],
+        expand((1997, 9, 22), "FREQ=MONTHLY;COUNT=6;BYDAY=-2MO", 100)
+    );
+
+    assert_eq!(
+        vec![
+            (1997, 9, 28),
+            (1997, 10, 29),
+            (1997, 11, 28),
+            (1997, 12, 29),
+            (1998, 1, 29),
+            (1998, 2, 26)
+        ],
+        expand((1997, 9, 28), "FREQ=MONTHLY;BYMONTHDAY=-3", 6)
+    );
+
+    assert_eq!(
+        vec![
+            (1997, 9, 2),
+            (1997, 9, 15),
+            (1997, 10, 2),
+            (1997, 10, 15),
+            (1997, 11, 2),
+            (1997, 11, 15),
+            (1997, 12, 2),
+            (1997, 12, 15),
+            (1998, 1, 2),
+            (1998, 1, 15),
+        ],
+        expand((1997, 9, 2), "FREQ=MONTHLY;COUNT=10;BYMONTHDAY=2,15", 100)
+    );
+
+    // Friday the 13th
+    assert_eq!(
+        vec![(1998, 2, 13), (1998, 3, 13), (1998, 11, 13), (1999, 8, 13), (2000, 10, 13)],
+        expand((1997, 9, 2), "FREQ=MONTHLY;BYDAY=FR;BYMONTHDAY=13", 5)
+    );
+
+    // the first Saturday that follows the first Sunday of the month
+    assert_eq!(
+        vec![
+            (1997, 9, 13),
+            (1997, 10, 11),
+            (1997, 11, 8),
+            (1997, 12, 13),
+            (1998, 1, 10),
+            (1998, 2, 7),
+            (1998, 3, 7),
+            (1998, 4, 11),
+            (1998, 5, 9),
+            (1998, 6, 13),
+        ],
+        expand((1997, 9, 13), "FREQ=MONTHLY;BYDAY=SA;BYMONTHDAY=7,8,9,10,11,12,13", 10)
+    );
+}
+
+#[test]
+fn set_positions() {
+    assert_eq!(
+        vec![(1997, 9, 4), (1997, 10, 7), (1997, 11, 6)],
+        expand((1997, 9, 4), "FREQ=MONTHLY;COUNT=3;BYDAY=TU,WE,TH;BYSETPOS=3", 100)
+    );
+
+    assert_eq!(
+        vec![
+            (1997, 9, 29),
+            (1997, 10, 30),
+            (1997, 11, 27),
+            (1997, 12, 30),
+            (1998, 1, 29),
+            (1998, 2, 26),
+            (1998, 3, 30),
+        ],
+        expand((1997, 9, 29), "FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-2", 7)
+    );
+}
+
+#[test]
+fn yearly() {
+    assert_eq!(
+        vec![
+            (1997, 6, 10),
+            (1997, 7, 10),
+            (1998, 6, 10),
+            (1998, 7, 10),
+            (1999, 6, 10),
+            (1999, 7, 10),
+            (2000, 6, 10),
+            (2000, 7, 10),
+            (2001, 6, 10),
+            (2001, 7, 10),
+        ],
+        expand((1997, 6, 10), "FREQ=YEARLY;COUNT=10;BYMONTH=6,7", 100)
+    );
+
+    // the 20th Monday of the year
+    assert_eq!(
+        vec![(1997, 5, 19), (1998, 5, 18), (1999, 5, 17)],
+        expand((1997, 5, 19), "FREQ=YEARLY;BYDAY=20MO", 3)
+    );
+
+    // the US presidential election day
+    assert_eq!(
+        vec![(1996, 11, 5), (2000, 11, 7), (2004, 11, 2)],
+        expand(
+            (1996, 11, 5),
+            "FREQ=YEARLY;INTERVAL=4;BYMONTH=11;BYDAY=TU;BYMONTHDAY=2,3,4,5,6,7,8",
+            3
+        )
+    );
+}
+
+#[test]
+fn invalid_dates() {
+    // the invalid dates are skipped rather than clamped
+    assert_eq!(
+        vec![(2007, 1, 15), (2007, 1, 30), (2007, 2, 15), (2007, 3, 15), (2007, 3, 30)],
+        expand((2007, 1, 15), "FREQ=MONTHLY;BYMONTHDAY=15,30;COUNT=5", 100)
+    );
+
+    assert_eq!(
+        vec![(2000, 2, 29), (2004, 2, 29), (2008, 2, 29)],
+        expand((2000, 2, 29), "FREQ=YEARLY;COUNT=3", 100)
+    );
+
+    // a rule which never matches ends
+    assert_eq!(0, expand((2000, 1, 1), "FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=30", 100).len());
+}
+
+#[test]
+fn time_zones() {
+    let timezone = FixedOffset::west_opt(5 * 3600).unwrap();
+    let dtstart = timezone.with_ymd_and_hms(1997, 9, 2, 9, 0, 0).unwrap();
+
+    let rule: RRule = "FREQ=DAILY;UNTIL=19970904T140000Z".parse().unwrap();
+
+    assert_eq!(
+        vec![
+            dtstart,
+            timezone.with_ymd_and_hms(1997, 9, 3, 9, 0, 0).unwrap(),
+            timezone.with_ymd_and_hms(1997, 9, 4, 9, 0, 0).unwrap(),
+        ],
+        rule.occurrences(dtstart).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn dst() {
+    let rule: RRule = "FREQ=DAILY;COUNT=3".parse().unwrap();
+
+    // 02:30 is skipped on 2024-03-31, so it is interpreted with the offset before the gap, i.e. 03:30 CEST
+    let dtstart = CentralEurope.with_ymd_and_hms(2024, 3, 30, 2, 30, 0).unwrap();
+
+    assert_eq!(
+        vec![
+            dtstart,
+            Utc.with_ymd_and_hms(2024, 3, 31, 1, 30, 0).unwrap().with_timezone(&CentralEurope),
+            CentralEurope.with_ymd_and_hms(2024, 4, 1, 2, 30, 0).unwrap(),
+        ],
+        rule.occurrences(dtstart).collect::<Vec<_>>()
+    );
+
+    // 02:30 occurs twice on 2024-10-27, and the first one is CEST
+    let dtstart = CentralEurope.with_ymd_and_hms(2024, 10, 26, 2, 30, 0).unwrap();
+
+    assert_eq!(
+        vec![
+            dtstart,
+            Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap().with_timezone(&CentralEurope),
+            CentralEurope.with_ymd_and_hms(2024, 10, 28, 2, 30, 0).unwrap(),
+        ],
+        rule.occurrences(dtstart).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn parse_errors() {
+    for (rule, error) in [
+        ("", ParseRRuleError::InvalidFormat),
+        ("COUNT=3", ParseRRuleError::InvalidFormat),
+        ("FREQ=DAILY;COUNT=3;UNTIL=19970904", ParseRRuleError::InvalidFormat),
+        ("FREQ=DAILY;FREQ=DAILY", ParseRRuleError::InvalidFormat),
+        ("FREQ=WEEKLY;BYDAY=1MO", ParseRRuleError::InvalidFormat),
+        ("FREQ=WEEKLY;BYMONTHDAY=1", ParseRRuleError::InvalidFormat),
+        ("FREQ=MONTHLY;BYSETPOS=1", ParseRRuleError::InvalidFormat),
+        ("FREQ=MONTHLY;BYDAY=XX", ParseRRuleError::InvalidFormat),
+        ("FREQ=MONTHLY;BYMONTHDAY=+-5", ParseRRuleError::InvalidFormat),
+        ("FREQ=MONTHLY;BYMONTHDAY=-+5", ParseRRuleError::InvalidFormat),
+        ("FREQ=MONTHLY;BYMONTHDAY=--5", ParseRRuleError::InvalidFormat),
+        ("FREQ=MONTHLY;BYDAY=+-1MO", ParseRRuleError::InvalidFormat),
+        ("FREQ=DAILY;COUNT=-", ParseRRuleError::InvalidFormat),
+        ("FREQ=DAILY;UNTIL=1997090", ParseRRuleError::InvalidFormat),
+        ("FREQ=DAILY;INTERVAL=0", ParseRRuleError::OutOfRange),
+        ("FREQ=YEARLY;BYMONTH=13", ParseRRuleError::OutOfRange),
+        ("FREQ=MONTHLY;BYMONTHDAY=0", ParseRRuleError::OutOfRange),
+        ("FREQ=YEARLY;BYDAY=54MO", ParseRRuleError::OutOfRange),
+        ("FREQ=DAILY;UNTIL=19970230", ParseRRuleError::OutOfRange),
+        ("FREQ=HOURLY", ParseRRuleError::Unsupported),
+        ("FREQ=DAILY;BYHOUR=9", ParseRRuleError::Unsupported),
+    ] {
+        assert_eq!(Err(error), rule.parse::<RRule>(), "{rule}");
+    }
+
+    assert!("FREQ=MONTHLY;BYMONTHDAY=+5,-5;BYDAY=+1MO".parse::<RRule>().is_ok());
+
+    let rule: RRule = "rrule:freq=monthly;interval=2;count=3".parse().unwrap();
+
+    assert_eq!(Frequency::Monthly, rule.frequency());
+    assert_eq!(2, rule.interval());
+    assert_eq!(Some(3), rule.count());
+}
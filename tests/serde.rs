@@ -0,0 +1,114 @@
+#![cfg(feature = "serde")]
+
+use date_differencer::*;
+use serde::{Deserialize, Serialize};
+
+#[test]
+fn struct_representation() {
+    let diff = DateTimeDiffResult {
+        years:       1,
+        months:      2,
+        days:        3,
+        hours:       4,
+        minutes:     5,
+        seconds:     6,
+        nanoseconds: 7,
+    };
+
+    let json = serde_json::to_string(&diff).unwrap();
+
+    assert_eq!(
+        r#"{"years":1,"months":2,"days":3,"hours":4,"minutes":5,"seconds":6,"nanoseconds":7}"#,
+        json
+    );
+    assert_eq!(diff, serde_json::from_str(&json).unwrap());
+
+    let diff = DateDiffResult::from(diff);
+
+    let json = serde_json::to_string(&diff).unwrap();
+
+    assert_eq!(r#"{"years":1,"months":2,"days":3}"#, json);
+    assert_eq!(diff, serde_json::from_str(&json).unwrap());
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize, Deserialize)]
+struct Periods {
+    #[serde(with = "date_differencer::serde_iso8601")]
+    date:      DateDiffResult,
+    #[serde(with = "date_differencer::serde_iso8601")]
+    date_time: DateTimeDiffResult,
+}
+
+#[test]
+fn iso8601_representation() {
+    let periods = Periods {
+        date:      DateDiffResult {
+            years: -1, months: -2, days: 0
+        },
+        date_time: DateTimeDiffResult {
+            hours: 1,
+            nanoseconds: 500000000,
+            ..DateTimeDiffResult::default()
+        },
+    };
+
+    let json = serde_json::to_string(&periods).unwrap();
+
+    assert_eq!(r#"{"date":"-P1Y2M","date_time":"PT1H0.5S"}"#, json);
+    assert_eq!(periods, serde_json::from_str(&json).unwrap());
+
+    assert!(serde_json::from_str::<Periods>(r#"{"date":"P1DT1H","date_time":"PT1H"}"#).is_err());
+}
+
+#[test]
+fn iso8601_representation_carried_nanoseconds() {
+    // non-canonical seconds and nanoseconds are deserialized to the same total
+    let periods = Periods {
+        date:      DateDiffResult::default(),
+        date_time: DateTimeDiffResult {
+            seconds: 1,
+            nanoseconds: -5,
+            ..DateTimeDiffResult::default()
+        },
+    };
+
+    let json = serde_json::to_string(&periods).unwrap();
+
+    assert_eq!(r#"{"date":"P0D","date_time":"PT0.999999995S"}"#, json);
+    assert_eq!(
+        Periods {
+            date:      DateDiffResult::default(),
+            date_time: DateTimeDiffResult {
+                nanoseconds: 999999995,
+                ..DateTimeDiffResult::default()
+            },
+        },
+        serde_json::from_str(&json).unwrap()
+    );
+
+    for date_time in [
+        DateTimeDiffResult {
+            seconds: i32::MAX,
+            nanoseconds: 1500000000,
+            ..DateTimeDiffResult::default()
+        },
+        DateTimeDiffResult {
+            seconds: i32::MIN,
+            nanoseconds: -999999999,
+            ..DateTimeDiffResult::default()
+        },
+    ] {
+        let periods = Periods {
+            date: DateDiffResult::default(),
+            date_time,
+        };
+
+        let json = serde_json::to_string(&periods).unwrap();
+
+        assert_eq!(periods, serde_json::from_str(&json).unwrap(), "{json}");
+    }
+
+    assert!(
+        serde_json::from_str::<Periods>(r#"{"date":"P0D","date_time":"PT2147483650.2S"}"#).is_err()
+    );
+}
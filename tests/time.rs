@@ -0,0 +1,151 @@
+#![cfg(all(feature = "chrono", feature = "time"))]
+
+mod common;
+
+use chrono::prelude::*;
+use common::*;
+use date_differencer::{time_backend as backend, *};
+use rand::Rng;
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+fn to_offset_date_time(date_time: DateTime<Utc>) -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp_nanos(date_time.timestamp_nanos_opt().unwrap() as i128)
+        .unwrap()
+}
+
+fn to_primitive_date_time(date_time: DateTime<Utc>) -> PrimitiveDateTime {
+    let v = to_offset_date_time(date_time);
+
+    PrimitiveDateTime::new(v.date(), v.time())
+}
+
+#[test]
+fn same_as_chrono() {
+    let mut rng = rand::rng();
+
+    for _ in 0..10000 {
+        let a = random_date();
+        let b = random_date();
+
+        let (ta, tb) = (to_primitive_date_time(a), to_primitive_date_time(b));
+
+        let diff = date_time_diff(a, b);
+
+        assert_eq!(diff, backend::date_time_diff(ta, tb), "{a} {b}");
+        assert_eq!(date_diff(a, b), backend::date_diff(ta, tb), "{a} {b}");
+        assert_eq!(
+            date_diff(
+                a.date_naive().and_time(NaiveTime::MIN),
+                b.date_naive().and_time(NaiveTime::MIN)
+            ),
+            backend::date_diff(ta.date(), tb.date()),
+            "{a} {b}"
+        );
+        assert_eq!(Some(tb), backend::add_date_time_diff(ta, &diff), "{a} {b}");
+
+        let random_diff = DateTimeDiffResult {
+            years:       rng.random_range(-10..=10),
+            months:      rng.random_range(-30..=30),
+            days:        rng.random_range(-100..=100),
+            hours:       rng.random_range(-100..=100),
+            minutes:     rng.random_range(-100..=100),
+            seconds:     rng.random_range(-100..=100),
+            nanoseconds: rng.random_range(-1000000000..=1000000000),
+        };
+
+        assert_eq!(
+            add_date_time_diff(a, &random_diff).single().map(to_primitive_date_time),
+            backend::add_date_time_diff(ta, &random_diff),
+            "{a} {random_diff:?}"
+        );
+    }
+}
+
+#[test]
+fn offset() {
+    let offset = UtcOffset::from_hms(-5, 30, 0).unwrap();
+
+    for _ in 0..1000 {
+        let a = random_date();
+        let b = random_date();
+
+        let (ta, tb) = (to_offset_date_time(a), to_offset_date_time(b));
+        let (oa, ob) = (ta.to_offset(offset), tb.to_offset(offset));
+
+        // on the wall clock of each offset
+        assert_eq!(
+            backend::date_time_diff(to_primitive_date_time(a), to_primitive_date_time(b)),
+            backend::date_time_diff(ta, tb),
+            "{a} {b}"
+        );
+
+        let diff = backend::date_time_diff(oa, ob);
+
+        let result = backend::add_date_time_diff(oa, &diff).unwrap();
+
+        assert_eq!(ob, result, "{a} {b}");
+        assert_eq!(offset, result.offset());
+    }
+}
+
+#[test]
+fn different_offsets() {
+    for _ in 0..10000 {
+        let a = random_fixed_offset_date();
+        let b = random_fixed_offset_date();
+
+        let oa = to_offset_date_time(a.with_timezone(&Utc))
+            .to_offset(UtcOffset::from_whole_seconds(a.offset().local_minus_utc()).unwrap());
+        let ob = to_offset_date_time(b.with_timezone(&Utc))
+            .to_offset(UtcOffset::from_whole_seconds(b.offset().local_minus_utc()).unwrap());
+
+        let diff = backend::date_time_diff(oa, ob);
+
+        // `to` is counted on the offset of `from`
+        assert_eq!(
+            date_time_diff_with_options(a, b, &DiffOptions::default()),
+            Some(diff.clone()),
+            "{a} {b}"
+        );
+
+        let result = backend::add_date_time_diff(oa, &diff).unwrap();
+
+        assert_eq!(ob, result, "{a} {b}");
+        assert_eq!(oa.offset(), result.offset());
+    }
+
+    let offset = UtcOffset::from_hms(9, 0, 0).unwrap();
+
+    // 2000-01-01 00:00 +09:00 is 1999-12-31 15:00 UTC
+    let from = PrimitiveDateTime::new(
+        Date::from_calendar_date(2000, Month::January, 1).unwrap(),
+        Time::MIDNIGHT,
+    )
+    .assume_offset(offset);
+    let to = PrimitiveDateTime::new(
+        Date::from_calendar_date(2000, Month::January, 1).unwrap(),
+        Time::MIDNIGHT,
+    )
+    .assume_utc();
+
+    assert_eq!(
+        DateTimeDiffResult {
+            hours: 9,
+            ..DateTimeDiffResult::default()
+        },
+        backend::date_time_diff(from, to)
+    );
+}
+
+#[test]
+fn out_of_range() {
+    let date = PrimitiveDateTime::MAX;
+
+    assert_eq!(
+        None,
+        backend::add_date_time_diff(date, &DateDiffResult {
+            days: 1,
+            ..DateDiffResult::default()
+        })
+    );
+}
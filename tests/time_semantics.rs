@@ -0,0 +1,499 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use chrono::{prelude::*, Duration, LocalResult};
+use common::*;
+use date_differencer::*;
+use rand::Rng;
+
+const SEMANTICS: [TimeSemantics; 3] =
+    [TimeSemantics::Hybrid, TimeSemantics::WallClock, TimeSemantics::Exact];
+
+fn diff_with(
+    from: DateTime<CentralEurope>,
+    to: DateTime<CentralEurope>,
+    time_semantics: TimeSemantics,
+) -> DateTimeDiffResult {
+    date_time_diff_with_options(from, to, &DiffOptions {
+        time_semantics,
+        ..DiffOptions::default()
+    })
+    .unwrap()
+}
+
+fn add_with(
+    from: DateTime<CentralEurope>,
+    date_time_diff: &DateTimeDiffResult,
+    time_semantics: TimeSemantics,
+) -> LocalResult<DateTime<CentralEurope>> {
+    add_date_time_diff_with_options(from, date_time_diff, &AddOptions {
+        time_semantics,
+        ..AddOptions::default()
+    })
+}
+
+#[test]
+fn across_dst() {
+    // 02:00 to 03:00 is skipped on 2024-03-31
+    let from = CentralEurope.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+    let to = CentralEurope.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap();
+
+    let one_day = DateTimeDiffResult {
+        days: 1,
+        ..DateTimeDiffResult::default()
+    };
+    let hours_23 = DateTimeDiffResult {
+        hours: 23,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!(one_day, diff_with(from, to, TimeSemantics::Hybrid));
+    assert_eq!(one_day, diff_with(from, to, TimeSemantics::WallClock));
+    assert_eq!(hours_23, diff_with(from, to, TimeSemantics::Exact));
+
+    // without days, the elapsed hours are exact
+    assert_eq!(
+        Some(hours_23.clone()),
+        date_time_diff_with_options(from, to, &DiffOptions {
+            largest_unit: Unit::Hours,
+            ..DiffOptions::default()
+        })
+    );
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            hours: 24,
+            ..DateTimeDiffResult::default()
+        }),
+        date_time_diff_with_options(from, to, &DiffOptions {
+            largest_unit: Unit::Hours,
+            time_semantics: TimeSemantics::WallClock,
+            ..DiffOptions::default()
+        })
+    );
+
+    let to = CentralEurope.with_ymd_and_hms(2024, 3, 31, 11, 30, 0).unwrap();
+
+    let hours_22_minutes_30 = DateTimeDiffResult {
+        hours: 22,
+        minutes: 30,
+        ..DateTimeDiffResult::default()
+    };
+
+    let hours_23_minutes_30 = DateTimeDiffResult {
+        hours: 23,
+        minutes: 30,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!(hours_22_minutes_30, diff_with(from, to, TimeSemantics::Hybrid));
+    assert_eq!(hours_23_minutes_30, diff_with(from, to, TimeSemantics::WallClock));
+    assert_eq!(hours_22_minutes_30, diff_with(from, to, TimeSemantics::Exact));
+
+    // `date_time_diff` counts on the wall clock
+    assert_eq!(hours_23_minutes_30, date_time_diff(from, to));
+
+    // 24 hours are 1 day 1 hour on the wall clock, and 1 day on the offset of `from`
+    let twenty_four_hours = DateTimeDiffResult {
+        hours: 24,
+        ..DateTimeDiffResult::default()
+    };
+
+    let to = CentralEurope.with_ymd_and_hms(2024, 3, 31, 13, 0, 0).unwrap();
+
+    assert_eq!(LocalResult::Single(to), add_with(from, &twenty_four_hours, TimeSemantics::Hybrid));
+    assert_eq!(LocalResult::Single(to), add_with(from, &twenty_four_hours, TimeSemantics::Exact));
+    assert_eq!(
+        LocalResult::Single(CentralEurope.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap()),
+        add_with(from, &twenty_four_hours, TimeSemantics::WallClock)
+    );
+    assert_eq!(
+        LocalResult::Single(CentralEurope.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap()),
+        add_date_time_diff(from, &twenty_four_hours)
+    );
+    assert_eq!(
+        LocalResult::Single(CentralEurope.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap()),
+        add_with(from, &one_day, TimeSemantics::Hybrid)
+    );
+    assert_eq!(LocalResult::Single(to), add_with(from, &one_day, TimeSemantics::Exact));
+}
+
+#[test]
+fn time_only_in_fold() {
+    // 02:30 CEST and 02:10 CET on 2024-10-27 are 40 minutes apart
+    let from = Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap().with_timezone(&CentralEurope);
+    let to = Utc.with_ymd_and_hms(2024, 10, 27, 1, 10, 0).unwrap().with_timezone(&CentralEurope);
+
+    let minutes_40 = DateTimeDiffResult {
+        minutes: 40,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!(minutes_40, diff_with(from, to, TimeSemantics::Hybrid));
+    assert_eq!(minutes_40, diff_with(from, to, TimeSemantics::Exact));
+
+    // 02:10 is 20 minutes before 02:30 on the wall clock
+    let minutes_minus_20 = DateTimeDiffResult {
+        minutes: -20,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!(minutes_minus_20, diff_with(from, to, TimeSemantics::WallClock));
+    assert_eq!(minutes_minus_20, date_time_diff(from, to));
+
+    // `from` is not resolved again on the wall clock, so it keeps its offset
+    assert_eq!(LocalResult::Single(to), add_with(from, &minutes_40, TimeSemantics::Hybrid));
+    assert_eq!(
+        Ok(to),
+        add_date_time_diff_disambiguated(
+            from,
+            &minutes_40,
+            &AddOptions::default(),
+            Disambiguation::Reject
+        )
+    );
+    assert_eq!(LocalResult::Single(to), add_with(from, &minutes_40, TimeSemantics::Exact));
+}
+
+#[test]
+fn fixed_offset() {
+    let mut rng = rand::rng();
+
+    for _ in 0..10000 {
+        let offset = FixedOffset::east_opt(rng.random_range(-56..=56) * 15 * 60).unwrap();
+
+        let a = random_date().with_timezone(&offset);
+        let b = random_date().with_timezone(&offset);
+
+        let diff = date_time_diff(a, b);
+
+        // the semantics only differ when the offsets differ
+        for time_semantics in SEMANTICS {
+            assert_eq!(
+                Some(diff.clone()),
+                date_time_diff_with_options(a, b, &DiffOptions {
+                    time_semantics,
+                    ..DiffOptions::default()
+                }),
+                "{a} {b} {time_semantics:?}"
+            );
+            assert_eq!(
+                LocalResult::Single(b),
+                add_date_time_diff_with_options(a, &diff, &AddOptions {
+                    time_semantics,
+                    ..AddOptions::default()
+                }),
+                "{a} {b} {time_semantics:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn different_offsets() {
+    for _ in 0..10000 {
+        let a = random_fixed_offset_date();
+        let b = random_fixed_offset_date();
+
+        let diff = date_time_diff_with_options(a, b, &DiffOptions::default()).unwrap();
+
+        // the offset of `a` does not change, so `b` is counted on it unless the wall clocks are compared as they are
+        for time_semantics in [TimeSemantics::Hybrid, TimeSemantics::Exact] {
+            assert_eq!(
+                Some(diff.clone()),
+                date_time_diff_with_options(a, b, &DiffOptions {
+                    time_semantics,
+                    ..DiffOptions::default()
+                }),
+                "{a} {b} {time_semantics:?}"
+            );
+        }
+
+        assert!(diff.hours.abs() < 24, "{a} {b}");
+        assert_eq!(
+            LocalResult::Single(b),
+            add_date_time_diff_with_options(a, &diff, &AddOptions::default()),
+            "{a} {b}"
+        );
+    }
+
+    let from = FixedOffset::east_opt(8 * 3600 + 30 * 60)
+        .unwrap()
+        .with_ymd_and_hms(1967, 3, 28, 9, 18, 42)
+        .unwrap();
+    let to = FixedOffset::west_opt(10 * 3600 + 45 * 60)
+        .unwrap()
+        .with_ymd_and_hms(2038, 11, 12, 15, 20, 57)
+        .unwrap();
+
+    // `to` is 2038-11-13 10:35:57 on +08:30
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            years: 71,
+            months: 7,
+            days: 16,
+            hours: 1,
+            minutes: 17,
+            seconds: 15,
+            ..DateTimeDiffResult::default()
+        }),
+        date_time_diff_with_options(from, to, &DiffOptions::default())
+    );
+}
+
+#[test]
+fn round_trip() {
+    for _ in 0..10000 {
+        let a = random_date().with_timezone(&CentralEurope);
+        let b = random_date().with_timezone(&CentralEurope);
+
+        for time_semantics in SEMANTICS {
+            let diff = diff_with(a, b, time_semantics);
+
+            // a wall clock in a fold is ambiguous, so `b` is one of the results
+            match add_with(a, &diff, time_semantics) {
+                LocalResult::Single(v) => assert_eq!(b, v, "{a} {b} {time_semantics:?}"),
+                LocalResult::Ambiguous(x, y) => {
+                    assert!(b == x || b == y, "{a} {b} {time_semantics:?}")
+                },
+                LocalResult::None => panic!("{a} {b} {time_semantics:?}"),
+            }
+        }
+    }
+}
+
+#[test]
+fn disambiguation() {
+    let one_day = DateDiffResult {
+        days: 1,
+        ..DateDiffResult::default()
+    };
+
+    let add = |from: DateTime<CentralEurope>, time_semantics, disambiguation| {
+        add_date_time_diff_disambiguated(
+            from,
+            &one_day,
+            &AddOptions {
+                time_semantics,
+                ..AddOptions::default()
+            },
+            disambiguation,
+        )
+    };
+
+    // 02:30 does not exist on 2024-03-31
+    let from = CentralEurope.with_ymd_and_hms(2024, 3, 30, 2, 30, 0).unwrap();
+
+    let forward = CentralEurope.with_ymd_and_hms(2024, 3, 31, 3, 30, 0).unwrap();
+    let backward = CentralEurope.with_ymd_and_hms(2024, 3, 31, 1, 30, 0).unwrap();
+
+    assert_eq!(LocalResult::None, add_date_time_diff(from, &one_day));
+
+    for time_semantics in [TimeSemantics::Hybrid, TimeSemantics::WallClock] {
+        assert_eq!(Ok(forward), add(from, time_semantics, Disambiguation::Compatible));
+        assert_eq!(Ok(forward), add(from, time_semantics, Disambiguation::Later));
+        assert_eq!(Ok(backward), add(from, time_semantics, Disambiguation::Earlier));
+        assert_eq!(
+            Err(DiffError::NonexistentLocalTime),
+            add(from, time_semantics, Disambiguation::Reject)
+        );
+    }
+
+    // 24 elapsed hours have nothing to resolve
+    assert_eq!(Ok(forward), add(from, TimeSemantics::Exact, Disambiguation::Reject));
+
+    // 02:30 is ambiguous on 2024-10-27
+    let from = CentralEurope.with_ymd_and_hms(2024, 10, 26, 2, 30, 0).unwrap();
+
+    let (earlier, later) = match add_date_time_diff(from, &one_day) {
+        LocalResult::Ambiguous(a, b) => (a, b),
+        v => panic!("{v:?}"),
+    };
+
+    assert_eq!(2 * 3600, earlier.offset().local_minus_utc());
+    assert_eq!(3600, later.offset().local_minus_utc());
+
+    for time_semantics in [TimeSemantics::Hybrid, TimeSemantics::WallClock] {
+        assert_eq!(Ok(earlier), add(from, time_semantics, Disambiguation::Compatible));
+        assert_eq!(Ok(earlier), add(from, time_semantics, Disambiguation::Earlier));
+        assert_eq!(Ok(later), add(from, time_semantics, Disambiguation::Later));
+        assert_eq!(
+            Err(DiffError::AmbiguousLocalTime),
+            add(from, time_semantics, Disambiguation::Reject)
+        );
+    }
+
+    // the time is added after the date-time is resolved
+    let hours = DateTimeDiffResult {
+        days: 1,
+        hours: 1,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!(
+        Ok(later + Duration::hours(1)),
+        add_date_time_diff_disambiguated(
+            from,
+            &hours,
+            &AddOptions::default(),
+            Disambiguation::Later
+        )
+    );
+}
+
+#[test]
+fn intermediate_in_gap() {
+    // 02:30 does not exist on 2024-03-31, so the day after `from` is 03:30 CEST
+    let from = CentralEurope.with_ymd_and_hms(2024, 3, 30, 2, 30, 0).unwrap();
+    let to = CentralEurope.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap();
+
+    let diff = DateTimeDiffResult {
+        days: 1,
+        hours: 8,
+        minutes: 30,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!(diff, diff_with(from, to, TimeSemantics::Hybrid));
+    assert_eq!(
+        Ok(to),
+        add_date_time_diff_disambiguated(
+            from,
+            &diff,
+            &AddOptions::default(),
+            Disambiguation::Compatible
+        )
+    );
+    assert_eq!(LocalResult::Single(to), add_with(from, &diff, TimeSemantics::Hybrid));
+
+    // 2024-03-31 02:42 is 03:42 CEST, 3 hours 39 minutes before 07:21 CEST
+    let from = CentralEurope.with_ymd_and_hms(2024, 3, 30, 2, 42, 0).unwrap();
+    let to = CentralEurope.with_ymd_and_hms(2024, 3, 31, 7, 21, 0).unwrap();
+
+    let diff = DateTimeDiffResult {
+        days: 1,
+        hours: 3,
+        minutes: 39,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!(diff, diff_with(from, to, TimeSemantics::Hybrid));
+    assert_eq!(LocalResult::Single(to), add_with(from, &diff, TimeSemantics::Hybrid));
+}
+
+#[test]
+fn disambiguation_same_as_local_result() {
+    let mut rng = rand::rng();
+
+    for _ in 0..10000 {
+        let from = random_date().with_timezone(&CentralEurope);
+
+        let diff = DateTimeDiffResult {
+            years:       rng.random_range(-10..=10),
+            months:      rng.random_range(-30..=30),
+            days:        rng.random_range(-100..=100),
+            hours:       rng.random_range(-100..=100),
+            minutes:     rng.random_range(-100..=100),
+            seconds:     rng.random_range(-100..=100),
+            nanoseconds: rng.random_range(-1000000000..=1000000000),
+        };
+
+        for time_semantics in SEMANTICS {
+            let options = AddOptions {
+                time_semantics,
+                ..AddOptions::default()
+            };
+
+            let result = add_date_time_diff_with_options(from, &diff, &options);
+
+            for disambiguation in [
+                Disambiguation::Compatible,
+                Disambiguation::Earlier,
+                Disambiguation::Later,
+                Disambiguation::Reject,
+            ] {
+                let v = add_date_time_diff_disambiguated(from, &diff, &options, disambiguation);
+
+                // the hybrid intermediate date-time is always resolved compatibly
+                if time_semantics == TimeSemantics::Hybrid {
+                    if disambiguation == Disambiguation::Compatible {
+                        assert_eq!(
+                            result,
+                            v.map_or(LocalResult::None, LocalResult::Single),
+                            "{from} {diff:?}"
+                        );
+                    }
+
+                    continue;
+                }
+
+                match (&result, disambiguation) {
+                    (LocalResult::Single(a), _) => assert_eq!(Ok(*a), v, "{from} {diff:?}"),
+                    (
+                        LocalResult::Ambiguous(a, _),
+                        Disambiguation::Compatible | Disambiguation::Earlier,
+                    ) => {
+                        assert_eq!(Ok(*a), v, "{from} {diff:?}")
+                    },
+                    (LocalResult::Ambiguous(_, b), Disambiguation::Later) => {
+                        assert_eq!(Ok(*b), v, "{from} {diff:?}")
+                    },
+                    (_, Disambiguation::Reject) => assert!(v.is_err(), "{from} {diff:?}"),
+                    (..) => assert!(v.is_ok(), "{from} {diff:?}"),
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn try_add() {
+    let one_day = DateDiffResult {
+        days: 1,
+        ..DateDiffResult::default()
+    };
+
+    let from = CentralEurope.with_ymd_and_hms(2024, 3, 30, 2, 30, 0).unwrap();
+
+    assert_eq!(Err(DiffError::NonexistentLocalTime), try_add_date_time_diff(from, &one_day));
+
+    let from = CentralEurope.with_ymd_and_hms(2024, 10, 26, 2, 30, 0).unwrap();
+
+    assert_eq!(Err(DiffError::AmbiguousLocalTime), try_add_date_time_diff(from, &one_day));
+
+    let from = Utc.with_ymd_and_hms(2021, 1, 31, 0, 0, 0).unwrap();
+
+    assert_eq!(
+        Ok(Utc.with_ymd_and_hms(2021, 2, 1, 0, 0, 0).unwrap()),
+        try_add_date_time_diff(from, &one_day)
+    );
+    assert_eq!(
+        Err(DiffError::Overflow),
+        try_add_date_time_diff(from, &DateTimeDiffResult {
+            years: i32::MAX,
+            ..DateTimeDiffResult::default()
+        })
+    );
+    assert_eq!(
+        Err(DiffError::OutOfChronoRange),
+        try_add_date_time_diff(from, &DateDiffResult {
+            years: 1000000,
+            ..DateDiffResult::default()
+        })
+    );
+    assert_eq!(
+        Err(DiffError::NonexistentDate),
+        add_date_time_diff_disambiguated(
+            from,
+            &DateDiffResult {
+                months: 1,
+                ..DateDiffResult::default()
+            },
+            &AddOptions {
+                end_of_month: EndOfMonth::Reject,
+                ..AddOptions::default()
+            },
+            Disambiguation::Compatible
+        )
+    );
+}
@@ -0,0 +1,160 @@
+#![cfg(all(feature = "chrono", feature = "std"))]
+
+mod common;
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use chrono::{prelude::*, LocalResult};
+use common::*;
+use date_differencer::*;
+use rand::Rng;
+
+fn to_system_time(timestamp: i64) -> SystemTime {
+    if timestamp >= 0 {
+        UNIX_EPOCH + Duration::from_nanos(timestamp as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_nanos(timestamp.unsigned_abs())
+    }
+}
+
+#[test]
+fn before_1970() {
+    // 1969-12-31T23:59:59.999999999Z and 1970-01-01T00:00:00Z
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            nanoseconds: 1,
+            ..DateTimeDiffResult::default()
+        }),
+        unix_timestamp_diff(-1, 0, &Utc)
+    );
+
+    // 1969-12-31T00:00:00Z and 1970-01-01T00:00:00Z
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            days: -1,
+            ..DateTimeDiffResult::default()
+        }),
+        unix_timestamp_diff(0, -86400 * 1000000000, &Utc)
+    );
+
+    let timezone = FixedOffset::east_opt(8 * 3600).unwrap();
+
+    // 1969-07-21T04:17:40+08:00 and 1970-01-01T07:59:59.999999999+08:00
+    let from = -14182940 * 1000000000;
+    let to = -1;
+
+    let diff = DateTimeDiffResult {
+        months: 5,
+        days: 11,
+        hours: 3,
+        minutes: 42,
+        seconds: 19,
+        nanoseconds: 999999999,
+        ..DateTimeDiffResult::default()
+    };
+
+    assert_eq!(Some(diff.clone()), unix_timestamp_diff(from, to, &timezone));
+    assert_eq!(LocalResult::Single(to), add_unix_timestamp_diff(from, &diff, &timezone));
+    assert_eq!(
+        Some(diff.clone().into_neg()),
+        system_time_diff(to_system_time(to), to_system_time(from), &timezone)
+    );
+}
+
+#[test]
+fn same_as_date_time() {
+    let mut rng = rand::rng();
+
+    for _ in 0..10000 {
+        let a = random_fixed_offset_date();
+        let b = random_fixed_offset_date().with_timezone(&a.timezone());
+
+        let (ta, tb) = (a.timestamp_nanos_opt().unwrap(), b.timestamp_nanos_opt().unwrap());
+
+        let diff = date_time_diff(a, b);
+
+        assert_eq!(Some(diff.clone()), unix_timestamp_diff(ta, tb, &a.timezone()), "{a} {b}");
+        assert_eq!(
+            LocalResult::Single(tb),
+            add_unix_timestamp_diff(ta, &diff, &a.timezone()),
+            "{a} {b}"
+        );
+
+        let (sa, sb) = (to_system_time(ta), to_system_time(tb));
+
+        assert_eq!(Some(diff.clone()), system_time_diff(sa, sb, &a.timezone()), "{a} {b}");
+        assert_eq!(
+            LocalResult::Single(sb),
+            add_system_time_diff(sa, &diff, &a.timezone()),
+            "{a} {b}"
+        );
+
+        let days = DateDiffResult {
+            days: rng.random_range(-1000..=1000),
+            ..DateDiffResult::default()
+        };
+
+        assert_eq!(
+            add_date_time_diff(a, &days).map(|v| v.timestamp_nanos_opt().unwrap()),
+            add_unix_timestamp_diff(ta, &days, &a.timezone()),
+            "{a} {days:?}"
+        );
+    }
+}
+
+#[test]
+fn same_as_date_time_across_dst() {
+    for _ in 0..10000 {
+        let a = random_date().with_timezone(&CentralEurope);
+        let b = random_date().with_timezone(&CentralEurope);
+
+        let (ta, tb) = (a.timestamp_nanos_opt().unwrap(), b.timestamp_nanos_opt().unwrap());
+
+        let diff = date_time_diff_with_options(a, b, &DiffOptions::default()).unwrap();
+
+        assert_eq!(Some(diff.clone()), unix_timestamp_diff(ta, tb, &CentralEurope), "{a} {b}");
+        assert_eq!(
+            add_date_time_diff_with_options(a, &diff, &AddOptions::default())
+                .map(|v| v.timestamp_nanos_opt().unwrap()),
+            add_unix_timestamp_diff(ta, &diff, &CentralEurope),
+            "{a} {b}"
+        );
+    }
+
+    // 02:30 CEST to 02:10 CET on 2024-10-27 are 40 minutes apart
+    let from = Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap().timestamp_nanos_opt().unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 10, 27, 1, 10, 0).unwrap().timestamp_nanos_opt().unwrap();
+
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            minutes: 40,
+            ..DateTimeDiffResult::default()
+        }),
+        unix_timestamp_diff(from, to, &CentralEurope)
+    );
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            minutes: -40,
+            ..DateTimeDiffResult::default()
+        }),
+        system_time_diff(to_system_time(to), to_system_time(from), &CentralEurope)
+    );
+    assert_eq!(
+        LocalResult::Single(to),
+        add_unix_timestamp_diff(
+            from,
+            &DateTimeDiffResult {
+                minutes: 40,
+                ..DateTimeDiffResult::default()
+            },
+            &CentralEurope
+        )
+    );
+}
+
+#[test]
+fn now() {
+    let now = SystemTime::now();
+
+    assert_eq!(Some(DateTimeDiffResult::default()), system_time_diff(now, now, &Local));
+}
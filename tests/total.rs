@@ -0,0 +1,136 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use chrono::prelude::*;
+use common::*;
+use date_differencer::*;
+
+#[test]
+fn fraction_new() {
+    let fraction = Fraction::new(-12, -18).unwrap();
+
+    assert_eq!((2, 3), (fraction.numerator(), fraction.denominator()));
+
+    let fraction = Fraction::new(12, -18).unwrap();
+
+    assert_eq!((-2, 3), (fraction.numerator(), fraction.denominator()));
+
+    let fraction = Fraction::new(0, -5).unwrap();
+
+    assert_eq!((0, 1), (fraction.numerator(), fraction.denominator()));
+
+    let fraction = Fraction::new(i128::MIN, 1).unwrap();
+
+    assert_eq!((i128::MIN, 1), (fraction.numerator(), fraction.denominator()));
+
+    let fraction = Fraction::new(i128::MIN, i128::MIN).unwrap();
+
+    assert_eq!((1, 1), (fraction.numerator(), fraction.denominator()));
+
+    let fraction = Fraction::new(1, i128::MIN + 1).unwrap();
+
+    assert_eq!((-1, i128::MAX), (fraction.numerator(), fraction.denominator()));
+
+    assert_eq!(None, Fraction::new(1, 0));
+    assert_eq!(None, Fraction::new(i128::MIN, -1));
+    assert_eq!(None, Fraction::new(1, i128::MIN));
+}
+
+#[test]
+fn fixed_units() {
+    let from = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2020, 1, 15, 6, 0, 0).unwrap();
+
+    assert_eq!(Some(2.0357142857142856), total_in(from, to, Unit::Weeks));
+    assert_eq!(Some(14.25), total_in(from, to, Unit::Days));
+    assert_eq!(Some(342.0), total_in(from, to, Unit::Hours));
+    assert_eq!(Some(-342.0), total_in(to, from, Unit::Hours));
+
+    assert_eq!(Fraction::new(57, 28), total_in_fraction(from, to, Unit::Weeks));
+}
+
+#[test]
+fn across_dst() {
+    // 02:00 to 03:00 is skipped on 2024-03-31
+    let from = CentralEurope.with_ymd_and_hms(2024, 3, 30, 12, 0, 0).unwrap();
+    let to = CentralEurope.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap();
+
+    assert_eq!(Some(23.0), total_in(from, to, Unit::Hours));
+    assert_eq!(Some(-1380.0), total_in(to, from, Unit::Minutes));
+    assert_eq!(Some(1.0), total_in(from, to, Unit::Days));
+}
+
+#[test]
+fn calendar_units() {
+    // 1 month, then 14 of the 29 days from 2020-02-29 to 2020-03-29
+    let from = Utc.with_ymd_and_hms(2020, 1, 29, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2020, 3, 14, 0, 0, 0).unwrap();
+
+    assert_eq!(Fraction::new(43, 29), total_in_fraction(from, to, Unit::Months));
+
+    // the leap year is the next calendar unit
+    let from = Utc.with_ymd_and_hms(1990, 1, 1, 0, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+
+    assert_eq!(Fraction::new(34 * 366 + 1, 366), total_in_fraction(from, to, Unit::Years));
+
+    // backwards, the next calendar unit is from 2020-07-02 12:00 to 2019-07-02 12:00
+    assert_eq!(
+        Fraction::new(-(2 * 366 + 367), 2 * 366),
+        total_in_fraction(
+            Utc.with_ymd_and_hms(2021, 7, 2, 12, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+            Unit::Years
+        )
+    );
+
+    assert_eq!(Some(0.0), total_in(from, from, Unit::Years));
+}
+
+#[test]
+fn total_matches_diff() {
+    for _ in 0..10000 {
+        let a = random_date();
+        let b = random_date();
+
+        let days = total_in_fraction(a, b, Unit::Days).unwrap();
+
+        assert_eq!(
+            (b.naive_utc() - a.naive_utc()).num_nanoseconds().unwrap() as i128 * days.denominator(),
+            days.numerator() * 86400000000000,
+            "{a} {b}"
+        );
+
+        let months = total_in_fraction(a, b, Unit::Months).unwrap();
+
+        let whole = months.numerator() / months.denominator();
+
+        // the whole months can be one more than `date_time_diff` when the day of month is clamped, e.g. 2012-11-30 + 603 months is 2063-02-28
+        let whole_date = add_date_time_diff(a, &DateDiffResult {
+            months: whole as i32,
+            ..DateDiffResult::default()
+        })
+        .unwrap();
+
+        if a <= b {
+            assert!(a <= whole_date && whole_date <= b, "{a} {b}");
+        } else {
+            assert!(b <= whole_date && whole_date <= a, "{a} {b}");
+        }
+
+        let floor = date_time_diff_with_options(a, b, &DiffOptions {
+            largest_unit: Unit::Months,
+            smallest_unit: Unit::Months,
+            rounding_mode: RoundingMode::Floor,
+            ..DiffOptions::default()
+        })
+        .unwrap();
+
+        assert_eq!(
+            floor.months as i128,
+            months.numerator().div_euclid(months.denominator()),
+            "{a} {b}"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+#![cfg(feature = "chrono")]
+#![allow(clippy::needless_update)]
+
+mod common;
+
+use chrono::prelude::*;
+use common::*;
+use date_differencer::*;
+
+#[test]
+fn basic_diff_1_week() {
+    let date = Local.with_ymd_and_hms(2001, 1, 1, 0, 0, 0).unwrap();
+    let date_plus = Local.with_ymd_and_hms(2001, 1, 8, 0, 0, 0).unwrap();
+
+    let expect_result = DateTimeWeeksDiffResult {
+        weeks: 1,
+        ..DateTimeWeeksDiffResult::default()
+    };
+
+    assert_eq!(expect_result, date_time_weeks_diff(date, date_plus));
+    assert_eq!(expect_result.into_neg(), date_time_weeks_diff(date_plus, date));
+}
+
+#[test]
+fn split_days() {
+    for _ in 0..10000 {
+        let a = random_local_date();
+        let b = random_local_date();
+
+        let diff = date_time_diff(a, b);
+        let weeks_diff = date_time_weeks_diff(a, b);
+
+        assert_eq!(diff.days / 7, weeks_diff.weeks);
+        assert_eq!(diff.days % 7, weeks_diff.days);
+        assert_eq!(diff, DateTimeDiffResult {
+            years:       weeks_diff.years,
+            months:      weeks_diff.months,
+            days:        weeks_diff.weeks * 7 + weeks_diff.days,
+            hours:       weeks_diff.hours,
+            minutes:     weeks_diff.minutes,
+            seconds:     weeks_diff.seconds,
+            nanoseconds: weeks_diff.nanoseconds,
+        });
+
+        assert!((add_date_time_diff(a, &weeks_diff).latest().unwrap()
+            ..=add_date_time_diff(a, &weeks_diff).earliest().unwrap())
+            .contains(&b));
+    }
+}
+
+#[test]
+fn largest_unit_weeks() {
+    for _ in 0..10000 {
+        let a = random_local_date();
+        let b = random_local_date();
+
+        let options = DiffOptions {
+            largest_unit: Unit::Weeks,
+            smallest_unit: Unit::Days,
+            ..DiffOptions::default()
+        };
+
+        let weeks_diff = date_time_weeks_diff_with_options(a, b, &options).unwrap();
+
+        let days = (b.date_naive() - a.date_naive()).num_days() as i32;
+
+        assert_eq!(0, weeks_diff.years);
+        assert_eq!(0, weeks_diff.months);
+        assert!(weeks_diff.days.abs() < 7);
+        assert!((weeks_diff.weeks * 7 + weeks_diff.days - days).abs() <= 1);
+
+        // no weeks component, so they are expressed in days
+        assert_eq!(
+            Some(DateTimeDiffResult {
+                days: weeks_diff.weeks * 7 + weeks_diff.days,
+                ..DateTimeDiffResult::default()
+            }),
+            date_time_diff_with_options(a, b, &options)
+        );
+    }
+}
+
+#[test]
+fn smallest_unit_weeks() {
+    let from = Local.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+    let to = Local.with_ymd_and_hms(2020, 3, 20, 0, 0, 0).unwrap();
+
+    let options = DiffOptions {
+        largest_unit: Unit::Years,
+        smallest_unit: Unit::Weeks,
+        ..DiffOptions::default()
+    };
+
+    assert_eq!(
+        Some(DateTimeWeeksDiffResult {
+            months: 2,
+            weeks: 2,
+            ..DateTimeWeeksDiffResult::default()
+        }),
+        date_time_weeks_diff_with_options(from, to, &options)
+    );
+
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            months: 2,
+            days: 14,
+            ..DateTimeDiffResult::default()
+        }),
+        date_time_diff_with_options(from, to, &options)
+    );
+}
@@ -0,0 +1,142 @@
+#![cfg(feature = "chrono")]
+
+mod common;
+
+use chrono::{prelude::*, Duration};
+use common::*;
+use date_differencer::*;
+use rand::Rng;
+
+fn window(open: u32, close: u32) -> Option<WorkingWindow> {
+    Some(WorkingWindow {
+        open:  NaiveTime::from_hms_opt(open, 0, 0).unwrap(),
+        close: NaiveTime::from_hms_opt(close, 0, 0).unwrap(),
+    })
+}
+
+#[test]
+fn office_hours() {
+    let hours = WorkingHours::weekdays(window(9, 18).unwrap());
+
+    let from = Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 3, 15, 12, 0, 0).unwrap();
+
+    // 10 weekdays
+    assert_eq!(Duration::hours(90), working_time_between(from, to, &hours));
+    assert_eq!(Duration::hours(-90), working_time_between(to, from, &hours));
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            hours: -90,
+            ..DateTimeDiffResult::default()
+        }),
+        working_time_diff(to, from, &hours)
+    );
+
+    // on a weekend
+    let from = Utc.with_ymd_and_hms(2024, 3, 2, 10, 0, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 3, 3, 23, 0, 0).unwrap();
+
+    assert_eq!(Duration::zero(), working_time_between(from, to, &hours));
+}
+
+#[test]
+fn overnight() {
+    let hours = WorkingHours {
+        windows: [window(22, 6), None, None, None, None, None, None]
+    };
+
+    // from Monday 23:30 to Tuesday 05:15
+    let from = Utc.with_ymd_and_hms(2024, 3, 4, 23, 30, 0).unwrap();
+    let to = Utc.with_ymd_and_hms(2024, 3, 5, 5, 15, 0).unwrap();
+
+    assert_eq!(
+        Some(DateTimeDiffResult {
+            hours: 5,
+            minutes: 45,
+            ..DateTimeDiffResult::default()
+        }),
+        working_time_diff(from, to, &hours)
+    );
+
+    // Tuesday opens nothing
+    let to = Utc.with_ymd_and_hms(2024, 3, 5, 23, 0, 0).unwrap();
+
+    assert_eq!(Duration::hours(6) + Duration::minutes(30), working_time_between(from, to, &hours));
+}
+
+#[test]
+fn dst() {
+    let hours = WorkingHours {
+        windows: [window(0, 0); 7]
+    };
+
+    // the wall clock skips 02:00 to 03:00
+    let from = CentralEurope.with_ymd_and_hms(2024, 3, 31, 0, 0, 0).unwrap();
+    let to = CentralEurope.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+
+    assert_eq!(Duration::hours(23), working_time_between(from, to, &hours));
+
+    // the wall clock repeats 02:00 to 03:00
+    let from = CentralEurope.with_ymd_and_hms(2024, 10, 27, 0, 0, 0).unwrap();
+    let to = CentralEurope.with_ymd_and_hms(2024, 10, 28, 0, 0, 0).unwrap();
+
+    assert_eq!(Duration::hours(25), working_time_between(from, to, &hours));
+
+    // the window opens in the gap, so it opens at 03:00
+    let hours = WorkingHours {
+        windows: [None, None, None, None, None, None, window(2, 4)]
+    };
+
+    let from = CentralEurope.with_ymd_and_hms(2024, 3, 30, 0, 0, 0).unwrap();
+    let to = CentralEurope.with_ymd_and_hms(2024, 4, 1, 0, 0, 0).unwrap();
+
+    assert_eq!(Duration::hours(1), working_time_between(from, to, &hours));
+
+    // the window contains the repeated hour
+    let from = CentralEurope.with_ymd_and_hms(2024, 10, 26, 0, 0, 0).unwrap();
+    let to = CentralEurope.with_ymd_and_hms(2024, 10, 28, 0, 0, 0).unwrap();
+
+    let hours = WorkingHours {
+        windows: [None, None, None, None, None, None, window(1, 4)]
+    };
+
+    assert_eq!(Duration::hours(4), working_time_between(from, to, &hours));
+}
+
+#[test]
+fn random() {
+    let mut rng = rand::rng();
+
+    let all_day = WorkingHours {
+        windows: [window(0, 0); 7]
+    };
+
+    let hours = WorkingHours {
+        windows: [
+            window(9, 18),
+            window(9, 18),
+            None,
+            window(22, 6),
+            window(1, 4),
+            None,
+            window(0, 0),
+        ],
+    };
+
+    for _ in 0..10000 {
+        let a = random_date().with_timezone(&CentralEurope);
+        let b = a + Duration::minutes(rng.random_range(-20000..=20000));
+        let c = b + Duration::minutes(rng.random_range(-20000..=20000));
+
+        // every instant is in the windows
+        assert_eq!(b - a, working_time_between(a, b, &all_day), "{a} {b}");
+
+        let ab = working_time_between(a, b, &hours);
+        let bc = working_time_between(b, c, &hours);
+        let ac = working_time_between(a, c, &hours);
+
+        assert_eq!(ac, ab + bc, "{a} {b} {c}");
+        assert_eq!(-ab, working_time_between(b, a, &hours), "{a} {b}");
+        assert!(ab.abs() <= (b - a).abs(), "{a} {b}");
+    }
+}